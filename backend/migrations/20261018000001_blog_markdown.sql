@@ -0,0 +1,3 @@
+-- Markdown source for blog posts. content_html stays the rendered, sanitized
+-- output served to readers; posts authored before this keep a NULL source.
+ALTER TABLE blog_posts ADD COLUMN IF NOT EXISTS content_markdown TEXT;
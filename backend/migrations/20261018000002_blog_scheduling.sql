@@ -0,0 +1,3 @@
+-- Scheduled publishing: a published post is only visible once published_at has
+-- passed, so every published row needs a timestamp.
+UPDATE blog_posts SET published_at = created_at WHERE published = true AND published_at IS NULL;
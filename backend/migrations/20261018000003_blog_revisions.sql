@@ -0,0 +1,56 @@
+-- ── Blog Post Revisions ─────────────────────────────────────
+-- Append-only history of every blog_posts row. Written by trigger so edits made
+-- through the admin API and through SQL migrations are both captured.
+
+CREATE TABLE IF NOT EXISTS blog_post_revisions (
+    id                  UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    post_id             UUID NOT NULL REFERENCES blog_posts(id) ON DELETE CASCADE,
+    revision_number     INTEGER NOT NULL,
+    slug                VARCHAR(300) NOT NULL,
+    title               VARCHAR(500) NOT NULL,
+    subtitle            VARCHAR(500),
+    category            VARCHAR(100) NOT NULL,
+    hero_image_url      TEXT,
+    content_html        TEXT NOT NULL,
+    content_markdown    TEXT,
+    excerpt             TEXT NOT NULL,
+    author_name         VARCHAR(200) NOT NULL,
+    read_time_minutes   INTEGER NOT NULL,
+    published           BOOLEAN NOT NULL,
+    published_at        TIMESTAMPTZ,
+    created_at          TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (post_id, revision_number)
+);
+
+CREATE OR REPLACE FUNCTION record_blog_post_revision() RETURNS TRIGGER AS $$
+BEGIN
+    INSERT INTO blog_post_revisions
+        (post_id, revision_number, slug, title, subtitle, category, hero_image_url,
+         content_html, content_markdown, excerpt, author_name, read_time_minutes,
+         published, published_at)
+    VALUES (
+        NEW.id,
+        COALESCE((SELECT MAX(revision_number) FROM blog_post_revisions WHERE post_id = NEW.id), 0) + 1,
+        NEW.slug, NEW.title, NEW.subtitle, NEW.category, NEW.hero_image_url,
+        NEW.content_html, NEW.content_markdown, NEW.excerpt, NEW.author_name, NEW.read_time_minutes,
+        NEW.published, NEW.published_at
+    );
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+DROP TRIGGER IF EXISTS trg_blog_post_revision ON blog_posts;
+CREATE TRIGGER trg_blog_post_revision
+    AFTER INSERT OR UPDATE ON blog_posts
+    FOR EACH ROW EXECUTE FUNCTION record_blog_post_revision();
+
+-- Existing posts start with their current content as revision 1
+INSERT INTO blog_post_revisions
+    (post_id, revision_number, slug, title, subtitle, category, hero_image_url,
+     content_html, content_markdown, excerpt, author_name, read_time_minutes,
+     published, published_at, created_at)
+SELECT id, 1, slug, title, subtitle, category, hero_image_url,
+       content_html, content_markdown, excerpt, author_name, read_time_minutes,
+       published, published_at, updated_at
+FROM blog_posts
+ON CONFLICT (post_id, revision_number) DO NOTHING;
@@ -0,0 +1,18 @@
+-- ── Blog Taxonomy ───────────────────────────────────────────
+-- Tags are many-to-many; category stays a single free-form column on the post.
+
+CREATE TABLE IF NOT EXISTS blog_tags (
+    id          UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    slug        VARCHAR(100) UNIQUE NOT NULL,
+    name        VARCHAR(100) NOT NULL,
+    created_at  TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE TABLE IF NOT EXISTS blog_post_tags (
+    post_id     UUID NOT NULL REFERENCES blog_posts(id) ON DELETE CASCADE,
+    tag_id      UUID NOT NULL REFERENCES blog_tags(id) ON DELETE CASCADE,
+    PRIMARY KEY (post_id, tag_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_blog_post_tags_tag ON blog_post_tags(tag_id);
+CREATE INDEX IF NOT EXISTS idx_blog_posts_category ON blog_posts(LOWER(category));
@@ -0,0 +1,13 @@
+-- ── Blog Full-Text Search ───────────────────────────────────
+-- Weighted: title (A) > subtitle, excerpt (B) > body text (C). Tags are
+-- stripped from content_html so markup never matches a query.
+
+ALTER TABLE blog_posts ADD COLUMN IF NOT EXISTS search_vector tsvector
+    GENERATED ALWAYS AS (
+        setweight(to_tsvector('english', coalesce(title, '')), 'A') ||
+        setweight(to_tsvector('english', coalesce(subtitle, '')), 'B') ||
+        setweight(to_tsvector('english', coalesce(excerpt, '')), 'B') ||
+        setweight(to_tsvector('english', regexp_replace(content_html, '<[^>]*>', ' ', 'g')), 'C')
+    ) STORED;
+
+CREATE INDEX IF NOT EXISTS idx_blog_posts_search ON blog_posts USING GIN (search_vector);
@@ -0,0 +1,2 @@
+-- Per-post metrics look up blog_read events by slug (event_label).
+CREATE INDEX IF NOT EXISTS idx_events_name_label ON analytics_events(event_name, event_label);
@@ -0,0 +1,38 @@
+-- ── Lead Pipeline ───────────────────────────────────────────
+-- new → contacted → qualified → pilot, with closed_lost reachable from any
+-- open stage. History and notes are append-only.
+
+ALTER TABLE contact_submissions
+    ADD COLUMN IF NOT EXISTS status VARCHAR(30) NOT NULL DEFAULT 'new'
+        CHECK (status IN ('new', 'contacted', 'qualified', 'pilot', 'closed_lost')),
+    ADD COLUMN IF NOT EXISTS owner VARCHAR(200),
+    ADD COLUMN IF NOT EXISTS status_changed_at TIMESTAMPTZ;
+
+UPDATE contact_submissions SET status_changed_at = created_at WHERE status_changed_at IS NULL;
+ALTER TABLE contact_submissions
+    ALTER COLUMN status_changed_at SET NOT NULL,
+    ALTER COLUMN status_changed_at SET DEFAULT NOW();
+
+CREATE INDEX IF NOT EXISTS idx_contact_status ON contact_submissions(status, created_at DESC);
+CREATE INDEX IF NOT EXISTS idx_contact_owner ON contact_submissions(owner) WHERE owner IS NOT NULL;
+
+CREATE TABLE IF NOT EXISTS contact_status_history (
+    id              UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    contact_id      UUID NOT NULL REFERENCES contact_submissions(id) ON DELETE CASCADE,
+    from_status     VARCHAR(30) NOT NULL,
+    to_status       VARCHAR(30) NOT NULL,
+    changed_by      VARCHAR(200),
+    created_at      TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_contact_status_history_contact ON contact_status_history(contact_id, created_at);
+
+CREATE TABLE IF NOT EXISTS contact_notes (
+    id              UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    contact_id      UUID NOT NULL REFERENCES contact_submissions(id) ON DELETE CASCADE,
+    author          VARCHAR(200),
+    body            TEXT NOT NULL,
+    created_at      TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_contact_notes_contact ON contact_notes(contact_id, created_at);
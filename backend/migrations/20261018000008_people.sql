@@ -0,0 +1,52 @@
+-- ── People ──────────────────────────────────────────────────
+-- One row per normalized email (trimmed, lowercased). Contact submissions and
+-- waitlist entries point at their person; anonymous analytics visitor IDs are
+-- linked when a form is submitted with one.
+
+CREATE TABLE IF NOT EXISTS people (
+    id              UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    email           VARCHAR(300) UNIQUE NOT NULL,
+    name            VARCHAR(200),
+    company         VARCHAR(200),
+    first_seen_at   TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    last_seen_at    TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE TABLE IF NOT EXISTS person_visitors (
+    person_id       UUID NOT NULL REFERENCES people(id) ON DELETE CASCADE,
+    visitor_id      VARCHAR(64) NOT NULL,
+    linked_at       TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (person_id, visitor_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_person_visitors_visitor ON person_visitors(visitor_id);
+
+ALTER TABLE contact_submissions
+    ADD COLUMN IF NOT EXISTS person_id UUID REFERENCES people(id) ON DELETE SET NULL,
+    ADD COLUMN IF NOT EXISTS visitor_id VARCHAR(64);
+ALTER TABLE waitlist_entries
+    ADD COLUMN IF NOT EXISTS person_id UUID REFERENCES people(id) ON DELETE SET NULL,
+    ADD COLUMN IF NOT EXISTS visitor_id VARCHAR(64);
+
+CREATE INDEX IF NOT EXISTS idx_contact_person ON contact_submissions(person_id);
+CREATE INDEX IF NOT EXISTS idx_waitlist_person ON waitlist_entries(person_id);
+
+-- Backfill from existing submissions; name/company come from the latest row
+INSERT INTO people (email, name, company, first_seen_at, last_seen_at)
+SELECT email,
+       (ARRAY_AGG(name ORDER BY created_at DESC) FILTER (WHERE name IS NOT NULL))[1],
+       (ARRAY_AGG(company ORDER BY created_at DESC) FILTER (WHERE company IS NOT NULL))[1],
+       MIN(created_at),
+       MAX(created_at)
+FROM (
+    SELECT LOWER(TRIM(email)) AS email, name, company, created_at FROM contact_submissions
+    UNION ALL
+    SELECT LOWER(TRIM(email)), name, company, created_at FROM waitlist_entries
+) s
+GROUP BY email
+ON CONFLICT (email) DO NOTHING;
+
+UPDATE contact_submissions c SET person_id = p.id
+FROM people p WHERE p.email = LOWER(TRIM(c.email)) AND c.person_id IS NULL;
+UPDATE waitlist_entries w SET person_id = p.id
+FROM people p WHERE p.email = LOWER(TRIM(w.email)) AND w.person_id IS NULL;
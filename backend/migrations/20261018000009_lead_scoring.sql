@@ -0,0 +1,18 @@
+-- ── Lead Scoring ────────────────────────────────────────────
+-- Score and a one-line breakdown on each contact, recomputed when the scoring
+-- model changes. The model itself is a single JSON document; an empty table
+-- means the built-in defaults apply.
+
+ALTER TABLE contact_submissions
+    ADD COLUMN IF NOT EXISTS score INTEGER,
+    ADD COLUMN IF NOT EXISTS score_explanation TEXT,
+    ADD COLUMN IF NOT EXISTS scored_at TIMESTAMPTZ;
+
+CREATE INDEX IF NOT EXISTS idx_contact_score ON contact_submissions(score DESC NULLS LAST, created_at DESC);
+
+CREATE TABLE IF NOT EXISTS lead_scoring_config (
+    id              BOOLEAN PRIMARY KEY DEFAULT TRUE CHECK (id),
+    config          JSONB NOT NULL,
+    updated_by      VARCHAR(200),
+    updated_at      TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
@@ -0,0 +1,15 @@
+-- ── Normalized Farm Fields ──────────────────────────────────
+-- Parsed acreage and crop alongside the raw free-text input. acreage_acres is
+-- derived so filters and scoring compare like with like. Existing rows are
+-- backfilled by the API at startup (the parser lives in Rust).
+
+ALTER TABLE contact_submissions
+    ADD COLUMN IF NOT EXISTS acreage_value DOUBLE PRECISION,
+    ADD COLUMN IF NOT EXISTS acreage_unit VARCHAR(10) CHECK (acreage_unit IN ('acres', 'hectares')),
+    ADD COLUMN IF NOT EXISTS acreage_acres DOUBLE PRECISION GENERATED ALWAYS AS (
+        CASE acreage_unit WHEN 'hectares' THEN acreage_value * 2.47105 ELSE acreage_value END
+    ) STORED,
+    ADD COLUMN IF NOT EXISTS crop_type_normalized VARCHAR(50);
+
+CREATE INDEX IF NOT EXISTS idx_contact_crop_type ON contact_submissions(crop_type_normalized);
+CREATE INDEX IF NOT EXISTS idx_contact_acreage_acres ON contact_submissions(acreage_acres);
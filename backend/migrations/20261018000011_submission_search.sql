@@ -0,0 +1,16 @@
+-- ── Submission Search & Paging ──────────────────────────────
+-- Trigram indexes back the admin ILIKE search on name, email and company;
+-- (created_at, id) indexes back keyset pagination.
+
+CREATE EXTENSION IF NOT EXISTS pg_trgm;
+
+CREATE INDEX IF NOT EXISTS idx_contact_name_trgm ON contact_submissions USING GIN (name gin_trgm_ops);
+CREATE INDEX IF NOT EXISTS idx_contact_email_trgm ON contact_submissions USING GIN (email gin_trgm_ops);
+CREATE INDEX IF NOT EXISTS idx_contact_company_trgm ON contact_submissions USING GIN (company gin_trgm_ops);
+CREATE INDEX IF NOT EXISTS idx_contact_keyset ON contact_submissions(created_at DESC, id DESC);
+CREATE INDEX IF NOT EXISTS idx_contact_source ON contact_submissions(source);
+
+CREATE INDEX IF NOT EXISTS idx_waitlist_name_trgm ON waitlist_entries USING GIN (name gin_trgm_ops);
+CREATE INDEX IF NOT EXISTS idx_waitlist_email_trgm ON waitlist_entries USING GIN (email gin_trgm_ops);
+CREATE INDEX IF NOT EXISTS idx_waitlist_company_trgm ON waitlist_entries USING GIN (company gin_trgm_ops);
+CREATE INDEX IF NOT EXISTS idx_waitlist_keyset ON waitlist_entries(created_at DESC, id DESC);
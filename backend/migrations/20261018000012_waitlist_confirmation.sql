@@ -0,0 +1,11 @@
+-- ── Waitlist Double Opt-In ──────────────────────────────────
+-- Entries stay unconfirmed until the subscriber clicks the emailed link.
+-- Signups from before double opt-in are treated as confirmed.
+
+ALTER TABLE waitlist_entries
+    ADD COLUMN IF NOT EXISTS confirmation_sent_at TIMESTAMPTZ,
+    ADD COLUMN IF NOT EXISTS confirmed_at TIMESTAMPTZ;
+
+UPDATE waitlist_entries SET confirmed_at = created_at WHERE confirmed_at IS NULL;
+
+CREATE INDEX IF NOT EXISTS idx_waitlist_confirmed ON waitlist_entries(confirmed_at) WHERE confirmed_at IS NOT NULL;
@@ -0,0 +1,16 @@
+-- ── Waitlist Referrals ──────────────────────────────────────
+-- Every entry gets a shareable referral code; signups arriving with ?ref=
+-- point at the entry that referred them.
+
+ALTER TABLE waitlist_entries
+    ADD COLUMN IF NOT EXISTS referral_code VARCHAR(16),
+    ADD COLUMN IF NOT EXISTS referred_by UUID REFERENCES waitlist_entries(id) ON DELETE SET NULL;
+
+UPDATE waitlist_entries
+SET referral_code = UPPER(SUBSTR(REPLACE(id::text, '-', ''), 1, 10))
+WHERE referral_code IS NULL;
+
+ALTER TABLE waitlist_entries ALTER COLUMN referral_code SET NOT NULL;
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_waitlist_referral_code ON waitlist_entries(referral_code);
+CREATE INDEX IF NOT EXISTS idx_waitlist_referred_by ON waitlist_entries(referred_by);
@@ -0,0 +1,36 @@
+-- ── Pilot Cohorts ───────────────────────────────────────────
+-- Pilot waitlist entries are placed into cohorts with a fixed number of
+-- seats. An entry moves pending → invited → accepted, or is declined or
+-- waitlisted along the way; invited and accepted entries hold a seat.
+
+CREATE TABLE IF NOT EXISTS pilot_cohorts (
+    id              UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    name            VARCHAR(200) NOT NULL UNIQUE,
+    interest        VARCHAR(100) NOT NULL DEFAULT 'pilot_2026',
+    region          VARCHAR(200),
+    crop_focus      VARCHAR(50),
+    capacity        INTEGER NOT NULL CHECK (capacity > 0),
+    starts_on       DATE,
+    created_at      TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at      TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+ALTER TABLE waitlist_entries
+    ADD COLUMN IF NOT EXISTS pilot_status VARCHAR(20) NOT NULL DEFAULT 'pending'
+        CHECK (pilot_status IN ('pending', 'invited', 'accepted', 'declined', 'waitlisted')),
+    ADD COLUMN IF NOT EXISTS cohort_id UUID REFERENCES pilot_cohorts(id) ON DELETE SET NULL,
+    ADD COLUMN IF NOT EXISTS pilot_status_changed_at TIMESTAMPTZ;
+
+CREATE INDEX IF NOT EXISTS idx_waitlist_cohort ON waitlist_entries(cohort_id, pilot_status) WHERE cohort_id IS NOT NULL;
+
+CREATE TABLE IF NOT EXISTS pilot_status_history (
+    id              UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    entry_id        UUID NOT NULL REFERENCES waitlist_entries(id) ON DELETE CASCADE,
+    cohort_id       UUID REFERENCES pilot_cohorts(id) ON DELETE SET NULL,
+    from_status     VARCHAR(20) NOT NULL,
+    to_status       VARCHAR(20) NOT NULL,
+    changed_by      VARCHAR(200),
+    created_at      TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_pilot_status_history_entry ON pilot_status_history(entry_id, created_at);
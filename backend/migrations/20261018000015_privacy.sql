@@ -0,0 +1,25 @@
+-- ── Privacy ─────────────────────────────────────────────────
+-- Unsubscribes are recorded per person. Erasing someone deletes their
+-- submissions and person record and anonymizes analytics from their visitor
+-- IDs; the audit row keeps only a hash of the email, so it can answer "was
+-- this address erased?" without holding on to the address itself.
+
+ALTER TABLE people
+    ADD COLUMN IF NOT EXISTS unsubscribed_at TIMESTAMPTZ,
+    ADD COLUMN IF NOT EXISTS erasure_requested_at TIMESTAMPTZ;
+
+CREATE TABLE IF NOT EXISTS data_erasures (
+    id                      UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    email_sha256            CHAR(64) NOT NULL,
+    requested_via           VARCHAR(20) NOT NULL CHECK (requested_via IN ('self_service', 'admin')),
+    performed_by            VARCHAR(200),
+    contacts_deleted        BIGINT NOT NULL,
+    waitlist_deleted        BIGINT NOT NULL,
+    visitor_ids             BIGINT NOT NULL,
+    page_views_anonymized   BIGINT NOT NULL,
+    events_anonymized       BIGINT NOT NULL,
+    created_at              TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_data_erasures_email ON data_erasures(email_sha256);
+CREATE INDEX IF NOT EXISTS idx_data_erasures_created ON data_erasures(created_at DESC);
@@ -0,0 +1,5 @@
+-- ── Subject Access Requests ─────────────────────────────────
+-- Throttles how often an export link can be emailed to one address.
+
+ALTER TABLE people
+    ADD COLUMN IF NOT EXISTS access_requested_at TIMESTAMPTZ;
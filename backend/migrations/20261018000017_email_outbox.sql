@@ -0,0 +1,33 @@
+-- ── Email Outbox ────────────────────────────────────────────
+-- Every outgoing email is written here by the request that triggers it and
+-- delivered by a background worker, so an SMTP outage or an instance shutting
+-- down no longer loses mail. Failed sends are retried with backoff; messages
+-- the server rejects outright, or that run out of attempts, go to 'dead'.
+
+CREATE TABLE IF NOT EXISTS email_outbox (
+    id                  UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    kind                VARCHAR(50) NOT NULL,
+    recipient           VARCHAR(320) NOT NULL,
+    subject             TEXT NOT NULL,
+    text_body           TEXT NOT NULL,
+    html_body           TEXT,
+    unsubscribe_url     TEXT,
+    -- The submission the email is about; its mail goes when it is erased
+    contact_id          UUID REFERENCES contact_submissions(id) ON DELETE CASCADE,
+    waitlist_entry_id   UUID REFERENCES waitlist_entries(id) ON DELETE CASCADE,
+    status              VARCHAR(20) NOT NULL DEFAULT 'pending'
+        CHECK (status IN ('pending', 'sent', 'dead')),
+    attempts            INTEGER NOT NULL DEFAULT 0,
+    -- Also pushed forward while a worker holds the message, so one that dies
+    -- mid-send is picked up again once this passes
+    next_attempt_at     TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    last_error          TEXT,
+    sent_at             TIMESTAMPTZ,
+    created_at          TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at          TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_email_outbox_due ON email_outbox(next_attempt_at) WHERE status = 'pending';
+CREATE INDEX IF NOT EXISTS idx_email_outbox_status ON email_outbox(status, created_at DESC);
+CREATE INDEX IF NOT EXISTS idx_email_outbox_contact ON email_outbox(contact_id) WHERE contact_id IS NOT NULL;
+CREATE INDEX IF NOT EXISTS idx_email_outbox_waitlist ON email_outbox(waitlist_entry_id) WHERE waitlist_entry_id IS NOT NULL;
@@ -0,0 +1,13 @@
+-- ── Auto-Replies ────────────────────────────────────────────
+-- Acknowledgement emails to form submitters, worded per contact source and
+-- waitlist interest. A single JSON document like the scoring model; an empty
+-- table means the built-in defaults apply.
+
+CREATE TABLE IF NOT EXISTS auto_reply_config (
+    id              BOOLEAN PRIMARY KEY DEFAULT TRUE CHECK (id),
+    config          JSONB NOT NULL,
+    updated_by      VARCHAR(200),
+    updated_at      TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_email_outbox_recipient ON email_outbox(LOWER(recipient), kind, created_at DESC);
@@ -0,0 +1,26 @@
+-- ── Notification Routing ────────────────────────────────────
+-- Which team members hear about new contacts and waitlist signups, as one JSON
+-- document like the auto-reply config; an empty table means everything goes
+-- to ADMIN_EMAIL as it happens.
+
+CREATE TABLE IF NOT EXISTS notification_routing_config (
+    id              BOOLEAN PRIMARY KEY DEFAULT TRUE CHECK (id),
+    config          JSONB NOT NULL,
+    updated_by      VARCHAR(200),
+    updated_at      TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Submissions waiting for a recipient's daily digest. Rows are kept once
+-- digested so an admin can see what went out; they go with their submission.
+CREATE TABLE IF NOT EXISTS notification_digest_items (
+    id                  UUID PRIMARY KEY DEFAULT uuid_generate_v4(),
+    recipient           VARCHAR(320) NOT NULL,
+    route               VARCHAR(200) NOT NULL,
+    contact_id          UUID REFERENCES contact_submissions(id) ON DELETE CASCADE,
+    waitlist_entry_id   UUID REFERENCES waitlist_entries(id) ON DELETE CASCADE,
+    created_at          TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    digested_at         TIMESTAMPTZ,
+    CHECK ((contact_id IS NULL) <> (waitlist_entry_id IS NULL))
+);
+
+CREATE INDEX IF NOT EXISTS idx_digest_items_pending ON notification_digest_items(created_at) WHERE digested_at IS NULL;
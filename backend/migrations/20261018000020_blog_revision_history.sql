@@ -0,0 +1,92 @@
+-- ── Blog Post Revision History ──────────────────────────────
+-- Revisions outlive their post: deleting a post now detaches its history
+-- instead of deleting it. Revisions also record the post's tags, and a new one
+-- is only written when the content changes, not on publish/unpublish.
+
+ALTER TABLE blog_post_revisions ALTER COLUMN post_id DROP NOT NULL;
+ALTER TABLE blog_post_revisions DROP CONSTRAINT IF EXISTS blog_post_revisions_post_id_fkey;
+ALTER TABLE blog_post_revisions
+    ADD CONSTRAINT blog_post_revisions_post_id_fkey
+    FOREIGN KEY (post_id) REFERENCES blog_posts(id) ON DELETE SET NULL;
+
+-- Tag names, sorted. NULL on revisions written before tags were recorded.
+ALTER TABLE blog_post_revisions ADD COLUMN IF NOT EXISTS tags TEXT[];
+
+-- Each post's latest revision matches its current content, tags included
+UPDATE blog_post_revisions r
+SET tags = (
+    SELECT COALESCE(array_agg(t.name ORDER BY t.name), '{}')
+    FROM blog_post_tags pt JOIN blog_tags t ON t.id = pt.tag_id
+    WHERE pt.post_id = r.post_id
+)
+WHERE r.tags IS NULL
+  AND r.revision_number = (SELECT MAX(revision_number) FROM blog_post_revisions WHERE post_id = r.post_id);
+
+-- Record the post as it stands, unless that matches its latest revision.
+-- Publish state is stored but not compared, so toggling it adds nothing.
+CREATE OR REPLACE FUNCTION snapshot_blog_post(p_post_id UUID) RETURNS VOID AS $$
+DECLARE
+    post        blog_posts%ROWTYPE;
+    post_tags   TEXT[];
+    latest      blog_post_revisions%ROWTYPE;
+BEGIN
+    SELECT * INTO post FROM blog_posts WHERE id = p_post_id;
+    IF NOT FOUND THEN
+        RETURN;
+    END IF;
+    SELECT COALESCE(array_agg(t.name ORDER BY t.name), '{}') INTO post_tags
+    FROM blog_post_tags pt JOIN blog_tags t ON t.id = pt.tag_id
+    WHERE pt.post_id = p_post_id;
+
+    SELECT * INTO latest FROM blog_post_revisions
+    WHERE post_id = p_post_id ORDER BY revision_number DESC LIMIT 1;
+    IF FOUND AND ROW(latest.slug, latest.title, latest.subtitle, latest.category, latest.hero_image_url,
+                     latest.content_html, latest.content_markdown, latest.excerpt, latest.author_name,
+                     latest.read_time_minutes, latest.tags)
+        IS NOT DISTINCT FROM ROW(post.slug, post.title, post.subtitle, post.category, post.hero_image_url,
+                                 post.content_html, post.content_markdown, post.excerpt, post.author_name,
+                                 post.read_time_minutes, post_tags) THEN
+        RETURN;
+    END IF;
+
+    INSERT INTO blog_post_revisions
+        (post_id, revision_number, slug, title, subtitle, category, hero_image_url,
+         content_html, content_markdown, excerpt, author_name, read_time_minutes,
+         published, published_at, tags)
+    VALUES (
+        post.id,
+        COALESCE(latest.revision_number, 0) + 1,
+        post.slug, post.title, post.subtitle, post.category, post.hero_image_url,
+        post.content_html, post.content_markdown, post.excerpt, post.author_name, post.read_time_minutes,
+        post.published, post.published_at, post_tags
+    );
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE OR REPLACE FUNCTION record_blog_post_revision() RETURNS TRIGGER AS $$
+BEGIN
+    PERFORM snapshot_blog_post(NEW.id);
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE OR REPLACE FUNCTION record_blog_post_tags_revision() RETURNS TRIGGER AS $$
+BEGIN
+    PERFORM snapshot_blog_post(COALESCE(NEW.post_id, OLD.post_id));
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+-- Deferred to commit, so a post and its tags written in one transaction make
+-- one revision that includes both
+DROP TRIGGER IF EXISTS trg_blog_post_revision ON blog_posts;
+CREATE CONSTRAINT TRIGGER trg_blog_post_revision
+    AFTER INSERT OR UPDATE ON blog_posts
+    DEFERRABLE INITIALLY DEFERRED
+    FOR EACH ROW EXECUTE FUNCTION record_blog_post_revision();
+
+DROP TRIGGER IF EXISTS trg_blog_post_tags_revision ON blog_post_tags;
+CREATE CONSTRAINT TRIGGER trg_blog_post_tags_revision
+    AFTER INSERT OR DELETE ON blog_post_tags
+    DEFERRABLE INITIALLY DEFERRED
+    FOR EACH ROW EXECUTE FUNCTION record_blog_post_tags_revision();
@@ -0,0 +1,43 @@
+-- ── Scoring Crop Ids & One-off Backfills ────────────────────
+-- Scoring used to match crop_weights keys as substrings of the free-text crop;
+-- it now looks up the normalized crop id. Rewrite a saved config's keys onto
+-- the ids they used to match, keeping the highest weight when several land on
+-- one id, as the substring match did. Keys that are already ids are kept.
+-- Anything left over is rejected when the config is loaded.
+
+WITH keyword_ids (keyword, crop_id) AS (
+    VALUES
+        ('almond', 'almonds'),
+        ('walnut', 'walnuts'),
+        ('pistachio', 'pistachios'),
+        ('grape', 'wine_grapes'),
+        ('grape', 'table_grapes'),
+        ('grape', 'grapes'),
+        ('wine', 'wine_grapes'),
+        ('citrus', 'citrus'),
+        ('berr', 'berries'),
+        ('cherr', 'cherries'),
+        ('apple', 'apples'),
+        ('stone fruit', 'stone_fruit'),
+        ('vegetable', 'vegetables')
+)
+UPDATE lead_scoring_config c
+SET config = jsonb_set(c.config, '{crop_weights}', (
+    SELECT COALESCE(jsonb_object_agg(crop_id, points), '{}'::jsonb)
+    FROM (
+        SELECT COALESCE(k.crop_id, w.key) AS crop_id, MAX(w.value::int) AS points
+        FROM jsonb_each_text(c.config->'crop_weights') w
+        LEFT JOIN keyword_ids k ON k.keyword = LOWER(TRIM(w.key))
+        GROUP BY 1
+    ) mapped
+))
+WHERE jsonb_typeof(c.config->'crop_weights') = 'object';
+
+-- Data fixes that run from application code exactly once per database. The
+-- row is claimed in the same transaction as the fix, so concurrent instances
+-- skip it and a failed run is retried.
+CREATE TABLE IF NOT EXISTS data_backfills (
+    name            VARCHAR(100) PRIMARY KEY,
+    rows_updated    BIGINT,
+    completed_at    TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
@@ -0,0 +1,15 @@
+-- ── Digest Email Links ──────────────────────────────────────
+-- A digest lists several submissions, so unlike other mail it can't carry one
+-- contact_id and cascade away with it. Each held item now records the digest
+-- email it went out in, which lets an erasure find every digest that mentions
+-- the person.
+
+ALTER TABLE notification_digest_items
+    ADD COLUMN IF NOT EXISTS outbox_email_id UUID REFERENCES email_outbox(id) ON DELETE SET NULL;
+
+CREATE INDEX IF NOT EXISTS idx_digest_items_outbox_email
+    ON notification_digest_items(outbox_email_id) WHERE outbox_email_id IS NOT NULL;
+
+-- Digests queued before this can't be traced back to the people in them.
+-- Delivered and dead ones are only a record by now, so let them go.
+DELETE FROM email_outbox WHERE kind = 'daily_digest' AND status <> 'pending';
@@ -0,0 +1,13 @@
+-- ── Verified Visitor Links ──────────────────────────────────
+-- Visitor IDs used to be linked to a person on every form post, so anyone who
+-- typed someone else's email could attach their own browsing to that person.
+-- Links are now made only when a signed confirmation link is opened. Keep the
+-- existing links that a confirmed waitlist signup vouches for and drop the rest.
+
+DELETE FROM person_visitors pv
+WHERE NOT EXISTS (
+    SELECT 1 FROM waitlist_entries w
+    WHERE w.person_id = pv.person_id
+      AND w.visitor_id = pv.visitor_id
+      AND w.confirmed_at IS NOT NULL
+);
@@ -0,0 +1,22 @@
+-- ── Deleted Post Revisions ──────────────────────────────────
+-- Deleting a post nulls its revisions' post_id, which left no way to find or
+-- bring back that history. Revisions now also remember the id of the post they
+-- belonged to once it's gone, so admins can list deleted posts and restore one
+-- under its old id with its history reattached.
+
+ALTER TABLE blog_post_revisions ADD COLUMN IF NOT EXISTS deleted_post_id UUID;
+
+CREATE INDEX IF NOT EXISTS idx_blog_post_revisions_deleted_post
+    ON blog_post_revisions(deleted_post_id) WHERE deleted_post_id IS NOT NULL;
+
+-- Revisions orphaned before this migration don't know their post; group them
+-- by slug, which is the best evidence left of which belonged together
+UPDATE blog_post_revisions r
+SET deleted_post_id = g.deleted_post_id
+FROM (
+    SELECT slug, uuid_generate_v4() AS deleted_post_id
+    FROM blog_post_revisions
+    WHERE post_id IS NULL AND deleted_post_id IS NULL
+    GROUP BY slug
+) g
+WHERE r.post_id IS NULL AND r.deleted_post_id IS NULL AND r.slug = g.slug;
@@ -1,13 +1,22 @@
+use axum::Json;
 use axum::extract::{Path, Query, State};
 use axum::http::HeaderMap;
-use axum::Json;
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+use futures_util::{Stream, StreamExt, TryStreamExt, stream};
 use serde::Deserialize;
+use sqlx::Acquire;
+use uuid::Uuid;
 use validator::Validate;
 
+use crate::AppState;
+use crate::email::auto_reply;
 use crate::errors::{ApiError, ApiResult};
+use crate::export::{self, BundleFormat, ExportFormat, ExportRow, XlsxExport};
 use crate::models::*;
+use crate::normalize::{CROP_TYPES, CropType};
 use crate::services;
-use crate::AppState;
+use crate::sitemap;
+use crate::tokens::{self, Purpose, TokenError};
 
 // ── Health ────────────────────────────────────
 
@@ -32,32 +41,82 @@ pub struct PaginationParams {
     pub offset: Option<i64>,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct BlogListParams {
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+    pub category: Option<String>,
+    pub tag: Option<String>,
+}
+
 pub async fn list_posts(
     State(state): State<AppState>,
-    Query(params): Query<PaginationParams>,
+    Query(params): Query<BlogListParams>,
 ) -> ApiResult<Json<Vec<BlogPostSummary>>> {
     let limit = params.limit.unwrap_or(10).min(50);
     let offset = params.offset.unwrap_or(0);
-    let posts = services::list_blog_posts(&state.db, limit, offset).await?;
+    let posts = services::list_blog_posts(
+        &state.db,
+        params.category.as_deref(),
+        params.tag.as_deref(),
+        limit,
+        offset,
+    )
+    .await?;
     Ok(Json(posts))
 }
 
+#[derive(Debug, Deserialize)]
+pub struct BlogSearchParams {
+    pub q: String,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
+pub async fn search_posts(
+    State(state): State<AppState>,
+    Query(params): Query<BlogSearchParams>,
+) -> ApiResult<Json<Vec<BlogSearchResult>>> {
+    let q = params.q.trim();
+    if q.is_empty() || q.len() > 200 {
+        return Err(ApiError::Validation(
+            "q must be 1-200 characters".to_string(),
+        ));
+    }
+    let limit = params.limit.unwrap_or(10).min(50);
+    let offset = params.offset.unwrap_or(0);
+    let results = services::search_blog_posts(&state.db, q, limit, offset).await?;
+    Ok(Json(results))
+}
+
 pub async fn get_post(
     State(state): State<AppState>,
     Path(slug): Path<String>,
-) -> ApiResult<Json<BlogPost>> {
+) -> ApiResult<Json<BlogPostPage>> {
     let post = services::get_blog_post_by_slug(&state.db, &slug).await?;
-    Ok(Json(post))
+    let related = services::related_blog_posts(&state.db, post.id, 3).await?;
+    let detail = services::blog_post_detail(&state.db, post).await?;
+    Ok(Json(BlogPostPage { detail, related }))
+}
+
+pub async fn list_categories(State(state): State<AppState>) -> ApiResult<Json<Vec<BlogCategory>>> {
+    let categories = services::list_blog_categories(&state.db).await?;
+    Ok(Json(categories))
+}
+
+pub async fn list_tags(State(state): State<AppState>) -> ApiResult<Json<Vec<BlogTag>>> {
+    let tags = services::list_blog_tags(&state.db).await?;
+    Ok(Json(tags))
 }
 
 // ── Anti-Spam ────────────────────────────────
 
 fn check_spam(honeypot: &Option<String>, form_loaded_at: &Option<i64>) -> ApiResult<()> {
-    if let Some(hp) = honeypot {
-        if !hp.is_empty() {
-            tracing::info!("Spam blocked: honeypot field filled");
-            return Err(ApiError::SpamDetected);
-        }
+    if let Some(hp) = honeypot
+        && !hp.is_empty()
+    {
+        tracing::info!("Spam blocked: honeypot field filled");
+        return Err(ApiError::SpamDetected);
     }
     if let Some(loaded_at) = form_loaded_at {
         let now_ms = chrono::Utc::now().timestamp_millis();
@@ -70,6 +129,25 @@ fn check_spam(honeypot: &Option<String>, form_loaded_at: &Option<i64>) -> ApiRes
     Ok(())
 }
 
+/// Queue follow-up mail in a savepoint of the submission's transaction. A
+/// routing, template or config error is logged and rolled back on its own; it
+/// never costs the visitor their submission.
+async fn queue_mail(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    what: &str,
+    work: impl AsyncFnOnce(&mut sqlx::Transaction<'_, sqlx::Postgres>) -> anyhow::Result<()>,
+) -> ApiResult<()> {
+    let mut savepoint = tx.begin().await?;
+    match work(&mut savepoint).await {
+        Ok(()) => savepoint.commit().await?,
+        Err(e) => {
+            tracing::error!("Failed to queue {what}: {e}");
+            savepoint.rollback().await?;
+        }
+    }
+    Ok(())
+}
+
 // ── Contact ───────────────────────────────────
 
 pub async fn submit_contact(
@@ -77,7 +155,8 @@ pub async fn submit_contact(
     Json(req): Json<ContactRequest>,
 ) -> ApiResult<Json<serde_json::Value>> {
     check_spam(&req.website, &req._form_loaded_at)?;
-    req.validate().map_err(|e| ApiError::Validation(e.to_string()))?;
+    req.validate()
+        .map_err(|e| ApiError::Validation(e.to_string()))?;
 
     let recent = services::count_recent_contacts(&state.db, &req.email, 60).await?;
     if recent >= 3 {
@@ -85,14 +164,40 @@ pub async fn submit_contact(
         return Err(ApiError::RateLimited);
     }
 
-    let submission = services::create_contact(&state.db, &req).await?;
+    // The submission and the emails it triggers are committed together; mail
+    // that can't be queued is logged rather than losing the submission
+    let mut tx = state.db.begin().await?;
+    let submission = services::create_contact(&mut tx, &req).await?;
 
-    let config = state.config.clone();
-    tokio::spawn(async move {
-        if let Err(e) = crate::email::notify_contact(&config, &submission).await {
-            tracing::warn!("Failed to send contact notification email: {e}");
+    // Score before notifying so the email carries it. Scoring runs in a
+    // savepoint, so a failure still saves and sends the unscored submission.
+    let mut savepoint = tx.begin().await?;
+    let scored = match services::get_scoring_config(&mut *savepoint).await {
+        Ok(scoring) => services::score_contact(&mut savepoint, &scoring.config, &submission).await,
+        Err(e) => Err(e),
+    };
+    let submission = match scored {
+        Ok(scored) => {
+            savepoint.commit().await?;
+            scored
         }
-    });
+        Err(e) => {
+            tracing::warn!("Failed to score contact {}: {e}", submission.id);
+            savepoint.rollback().await?;
+            submission
+        }
+    };
+    let config = &state.config;
+    queue_mail(&mut tx, "contact notification", async |tx| {
+        crate::email::notify_contact(tx, config, &submission).await
+    })
+    .await?;
+    queue_mail(&mut tx, "contact auto-reply", async |tx| {
+        auto_reply::reply_to_contact(tx, config, &submission).await
+    })
+    .await?;
+    tx.commit().await?;
+    state.outbox.wake();
 
     Ok(Json(serde_json::json!({
         "success": true,
@@ -107,7 +212,8 @@ pub async fn submit_waitlist(
     Json(req): Json<WaitlistRequest>,
 ) -> ApiResult<Json<serde_json::Value>> {
     check_spam(&req.website, &req._form_loaded_at)?;
-    req.validate().map_err(|e| ApiError::Validation(e.to_string()))?;
+    req.validate()
+        .map_err(|e| ApiError::Validation(e.to_string()))?;
 
     let recent = services::count_recent_waitlist(&state.db, &req.email, 60).await?;
     if recent >= 3 {
@@ -115,57 +221,415 @@ pub async fn submit_waitlist(
         return Err(ApiError::RateLimited);
     }
 
-    let entry = services::create_waitlist_entry(&state.db, &req).await?;
+    let mut tx = state.db.begin().await?;
+    let entry = services::create_waitlist_entry(&mut tx, &req).await?;
 
-    let config = state.config.clone();
-    tokio::spawn(async move {
-        if let Err(e) = crate::email::notify_waitlist(&config, &entry).await {
-            tracing::warn!("Failed to send waitlist notification email: {e}");
+    // The admin hears about the signup once the subscriber confirms. The reply is the same
+    // whether or not the address was already on the list, so it can't be used to probe it.
+    let config = &state.config;
+    queue_mail(&mut tx, "waitlist confirmation", async |tx| {
+        if services::claim_waitlist_confirmation(&mut **tx, entry.id).await? {
+            let token = tokens::sign(
+                &config.token_secret,
+                Purpose::WaitlistConfirm,
+                &entry.id.to_string(),
+            );
+            let link = format!(
+                "{}/waitlist/confirm?token={token}",
+                config.app_base_url.trim_end_matches('/')
+            );
+            crate::email::send_waitlist_confirmation(tx, config, &entry, &link).await?;
         }
-    });
+        Ok(())
+    })
+    .await?;
+    tx.commit().await?;
+    state.outbox.wake();
+
+    Ok(Json(serde_json::json!({
+        "success": true,
+        "message": "Thanks! Check your inbox for a link to confirm your email and hold your spot.",
+    })))
+}
+
+pub async fn confirm_waitlist(
+    State(state): State<AppState>,
+    Json(req): Json<WaitlistConfirmRequest>,
+) -> ApiResult<Json<serde_json::Value>> {
+    req.validate()
+        .map_err(|e| ApiError::Validation(e.to_string()))?;
+    let id = tokens::verify(
+        &state.config.token_secret,
+        Purpose::WaitlistConfirm,
+        &req.token,
+    )
+    .map_err(|e| match e {
+        TokenError::Expired => ApiError::Validation(
+            "This confirmation link has expired. Sign up again to get a new one.".to_string(),
+        ),
+        TokenError::Invalid => {
+            ApiError::Validation("This confirmation link is invalid.".to_string())
+        }
+    })?
+    .parse::<Uuid>()
+    .map_err(|_| ApiError::Validation("This confirmation link is invalid.".to_string()))?;
+
+    let mut tx = state.db.begin().await?;
+    let (entry, newly_confirmed) = services::confirm_waitlist_entry(&mut tx, id).await?;
+    // Opening the signed link proves this browser belongs to the address's owner
+    if let (Some(person_id), Some(visitor_id)) = (entry.person_id, req.visitor_id.as_deref()) {
+        services::link_visitor(&mut *tx, person_id, visitor_id).await?;
+    }
+    let referral_code = entry.referral_code.clone();
+    if newly_confirmed {
+        let config = &state.config;
+        queue_mail(&mut tx, "waitlist notification", async |tx| {
+            crate::email::notify_waitlist(tx, config, &entry).await
+        })
+        .await?;
+        queue_mail(&mut tx, "waitlist auto-reply", async |tx| {
+            auto_reply::reply_to_waitlist(tx, config, &entry).await
+        })
+        .await?;
+    }
+    tx.commit().await?;
+    state.outbox.wake();
 
     Ok(Json(serde_json::json!({
         "success": true,
-        "message": "You're on the list! We'll reach out when your spot is ready."
+        "message": "You're confirmed! We'll reach out when your spot is ready.",
+        "referral_code": referral_code,
     })))
 }
 
+/// Queue position and referral count for the holder of a referral code.
+pub async fn waitlist_status(
+    State(state): State<AppState>,
+    Path(code): Path<String>,
+) -> ApiResult<Json<WaitlistStatus>> {
+    let status = services::waitlist_status(&state.db, &code).await?;
+    Ok(Json(status))
+}
+
+// ── Privacy ───────────────────────────────────
+
+pub async fn unsubscribe(
+    State(state): State<AppState>,
+    Json(req): Json<PrivacyTokenRequest>,
+) -> ApiResult<Json<serde_json::Value>> {
+    req.validate()
+        .map_err(|e| ApiError::Validation(e.to_string()))?;
+    let email = verify_privacy_token(&state, Purpose::Unsubscribe, &req.token)?;
+    services::unsubscribe(&state.db, &email).await?;
+    tracing::info!("Unsubscribe recorded");
+    Ok(Json(serde_json::json!({
+        "success": true,
+        "message": "You've been unsubscribed and won't get further emails from us."
+    })))
+}
+
+/// Start a "delete my data" request. The response is the same whether or not
+/// we hold anything for the address, so it can't be used to probe for people.
+pub async fn request_erasure(
+    State(state): State<AppState>,
+    Json(req): Json<PrivacyRequest>,
+) -> ApiResult<Json<serde_json::Value>> {
+    check_spam(&req.website, &req._form_loaded_at)?;
+    req.validate()
+        .map_err(|e| ApiError::Validation(e.to_string()))?;
+
+    if services::claim_erasure_request(&state.db, &req.email).await? {
+        let config = &state.config;
+        let email = services::normalize_email(&req.email);
+        let token = tokens::sign(&config.token_secret, Purpose::DataErasure, &email);
+        let link = format!(
+            "{}/privacy/delete?token={token}",
+            config.app_base_url.trim_end_matches('/')
+        );
+        if let Err(e) = crate::email::send_erasure_link(&state.outbox, &email, &link).await {
+            tracing::error!("Failed to queue erasure link: {e}");
+        }
+    }
+
+    Ok(Json(serde_json::json!({
+        "success": true,
+        "message": concat!(
+            "If we hold any data for that address, ",
+            "we've emailed it a link to confirm the deletion.",
+        ),
+    })))
+}
+
+pub async fn confirm_erasure(
+    State(state): State<AppState>,
+    Json(req): Json<PrivacyTokenRequest>,
+) -> ApiResult<Json<serde_json::Value>> {
+    req.validate()
+        .map_err(|e| ApiError::Validation(e.to_string()))?;
+    let email = verify_privacy_token(&state, Purpose::DataErasure, &req.token)?;
+    let erasure =
+        services::erase_personal_data(&state.db, &email, ErasureVia::SelfService, None).await?;
+    tracing::info!("Data erasure {} completed (self-service)", erasure.id);
+    Ok(Json(serde_json::json!({
+        "success": true,
+        "message": "Your data has been deleted."
+    })))
+}
+
+/// Start a subject access request; answered like `request_erasure`.
+pub async fn request_access(
+    State(state): State<AppState>,
+    Json(req): Json<PrivacyRequest>,
+) -> ApiResult<Json<serde_json::Value>> {
+    check_spam(&req.website, &req._form_loaded_at)?;
+    req.validate()
+        .map_err(|e| ApiError::Validation(e.to_string()))?;
+
+    if services::claim_access_request(&state.db, &req.email).await? {
+        let config = &state.config;
+        let email = services::normalize_email(&req.email);
+        let token = tokens::sign(&config.token_secret, Purpose::DataAccess, &email);
+        let link = format!(
+            "{}/privacy/export?token={token}",
+            config.app_base_url.trim_end_matches('/')
+        );
+        if let Err(e) = crate::email::send_access_link(&state.outbox, &email, &link).await {
+            tracing::error!("Failed to queue access export link: {e}");
+        }
+    }
+
+    Ok(Json(serde_json::json!({
+        "success": true,
+        "message": concat!(
+            "If we hold any data for that address, ",
+            "we've emailed it a link to download a copy.",
+        ),
+    })))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AccessExportParams {
+    pub token: String,
+    #[serde(default)]
+    pub format: BundleFormat,
+}
+
+/// Download the bundle behind an emailed access link. Read-only, so the link
+/// can be opened as often as needed until it expires.
+pub async fn access_export(
+    State(state): State<AppState>,
+    Query(params): Query<AccessExportParams>,
+) -> ApiResult<axum::response::Response> {
+    let email = verify_privacy_token(&state, Purpose::DataAccess, &params.token)?;
+    let bundle = services::subject_access_bundle(&state.db, &email).await?;
+    tracing::info!("Subject access export downloaded (self-service)");
+    bundle_response(&bundle, params.format)
+}
+
+fn bundle_response(
+    bundle: &SubjectAccessBundle,
+    format: BundleFormat,
+) -> ApiResult<axum::response::Response> {
+    use axum::http::header;
+    use axum::response::IntoResponse;
+
+    let body = match format {
+        BundleFormat::Json => export::subject_access_json(bundle)?,
+        BundleFormat::Zip => export::subject_access_zip(bundle)?,
+    };
+    let filename = format!(
+        "terroir-data-{}.{}",
+        bundle.generated_at.format("%Y-%m-%d"),
+        format.extension()
+    );
+    Ok((
+        [
+            (header::CONTENT_TYPE, format.content_type().to_string()),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"{filename}\""),
+            ),
+            (header::CACHE_CONTROL, "no-store".to_string()),
+        ],
+        body,
+    )
+        .into_response())
+}
+
+fn verify_privacy_token(state: &AppState, purpose: Purpose, token: &str) -> ApiResult<String> {
+    tokens::verify(&state.config.token_secret, purpose, token).map_err(|e| match e {
+        TokenError::Expired => ApiError::Validation("This link has expired.".to_string()),
+        TokenError::Invalid => ApiError::Validation("This link is invalid.".to_string()),
+    })
+}
+
 // ── Dynamic Sitemap ──────────────────────────
 
 pub async fn dynamic_sitemap(
     State(state): State<AppState>,
+    headers: HeaderMap,
+) -> ApiResult<axum::response::Response> {
+    let stats = services::sitemap_stats(&state.db).await?;
+    let total_urls =
+        stats.post_count + sitemap::static_pages(&state.config.app_base_url).len() as i64;
+    let etag = format!(
+        "W/\"{}-{}\"",
+        stats.post_count,
+        stats.last_modified.map_or(0, |d| d.timestamp())
+    );
+    if not_modified(&headers, &etag, stats.last_modified) {
+        return Ok(sitemap_not_modified(&etag));
+    }
+
+    let xml = if total_urls > sitemap::MAX_URLS_PER_SITEMAP {
+        let pages = sitemap::page_count(total_urls);
+        let api_base = format!("{}/api/v1", state.config.app_base_url.trim_end_matches('/'));
+        sitemap::index(&api_base, pages, stats.last_modified)
+    } else {
+        sitemap::urlset(&sitemap_page_urls(&state, 1).await?)
+    };
+    Ok(sitemap_response(xml, &etag, stats.last_modified))
+}
+
+/// One child sitemap (`/sitemaps/{n}.xml`) of the index served once the site
+/// outgrows a single file.
+pub async fn sitemap_page(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(file): Path<String>,
+) -> ApiResult<axum::response::Response> {
+    let page = file
+        .strip_suffix(".xml")
+        .and_then(|n| n.parse::<i64>().ok())
+        .filter(|&n| n >= 1)
+        .ok_or_else(|| ApiError::NotFound(format!("Sitemap '{file}' not found")))?;
+
+    let stats = services::sitemap_stats(&state.db).await?;
+    let etag = format!(
+        "W/\"{}-{}-{page}\"",
+        stats.post_count,
+        stats.last_modified.map_or(0, |d| d.timestamp())
+    );
+    if not_modified(&headers, &etag, stats.last_modified) {
+        return Ok(sitemap_not_modified(&etag));
+    }
+
+    let urls = sitemap_page_urls(&state, page).await?;
+    if urls.is_empty() {
+        return Err(ApiError::NotFound(format!("Sitemap '{file}' not found")));
+    }
+    Ok(sitemap_response(
+        sitemap::urlset(&urls),
+        &etag,
+        stats.last_modified,
+    ))
+}
+
+/// URLs for 1-based sitemap `page`: static pages first, then posts, newest first.
+async fn sitemap_page_urls(state: &AppState, page: i64) -> ApiResult<Vec<sitemap::SitemapUrl>> {
+    let base = &state.config.app_base_url;
+    let statics = sitemap::static_pages(base);
+    let (post_offset, post_limit) = sitemap::post_window(page, statics.len() as i64);
+
+    let mut urls = if page == 1 { statics } else { Vec::new() };
+    let posts = services::list_sitemap_posts(&state.db, post_limit, post_offset).await?;
+    urls.extend(
+        posts
+            .into_iter()
+            .map(|p| sitemap::blog_post_url(base, &p.slug, p.updated_at)),
+    );
+    Ok(urls)
+}
+
+fn not_modified(headers: &HeaderMap, etag: &str, last_modified: Option<DateTime<Utc>>) -> bool {
+    use axum::http::header;
+
+    if let Some(inm) = headers
+        .get(header::IF_NONE_MATCH)
+        .and_then(|v| v.to_str().ok())
+    {
+        return inm.split(',').any(|t| t.trim() == etag || t.trim() == "*");
+    }
+    match (
+        headers
+            .get(header::IF_MODIFIED_SINCE)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| DateTime::parse_from_rfc2822(v).ok()),
+        last_modified,
+    ) {
+        (Some(since), Some(modified)) => modified.timestamp() <= since.timestamp(),
+        _ => false,
+    }
+}
+
+fn sitemap_response(
+    xml: String,
+    etag: &str,
+    last_modified: Option<DateTime<Utc>>,
 ) -> axum::response::Response {
-    use axum::http::{header, StatusCode};
+    use axum::http::{HeaderValue, StatusCode, header};
     use axum::response::IntoResponse;
 
-    let base = "https://terroirai.com";
-    let mut xml = String::from(
-        r#"<?xml version="1.0" encoding="UTF-8"?>
-<urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">
-  <url><loc>BASE/</loc><changefreq>weekly</changefreq><priority>1.0</priority></url>
-  <url><loc>BASE/blog</loc><changefreq>weekly</changefreq><priority>0.8</priority></url>
-  <url><loc>BASE/contact</loc><changefreq>monthly</changefreq><priority>0.7</priority></url>
-"#
-    ).replace("BASE", base);
-
-    // Fetch published blog posts for dynamic entries
-    if let Ok(posts) = services::list_blog_posts(&state.db, 100, 0).await {
-        for post in posts {
-            xml.push_str(&format!(
-                "  <url><loc>{base}/blog/{slug}</loc><lastmod>{date}</lastmod><changefreq>monthly</changefreq><priority>0.6</priority></url>\n",
-                slug = post.slug,
-                date = post.published_at.map(|d| d.to_string()).unwrap_or_default(),
-            ));
-        }
+    let mut response = (
+        StatusCode::OK,
+        [
+            (header::CONTENT_TYPE, "application/xml; charset=utf-8"),
+            (header::CACHE_CONTROL, "public, max-age=3600"),
+        ],
+        xml,
+    )
+        .into_response();
+    let h = response.headers_mut();
+    if let Ok(v) = HeaderValue::from_str(etag) {
+        h.insert(header::ETAG, v);
+    }
+    if let Some(modified) = last_modified
+        && let Ok(v) = HeaderValue::from_str(&http_date(modified))
+    {
+        h.insert(header::LAST_MODIFIED, v);
     }
+    response
+}
 
-    xml.push_str("</urlset>");
+fn sitemap_not_modified(etag: &str) -> axum::response::Response {
+    use axum::http::{StatusCode, header};
+    use axum::response::IntoResponse;
 
-    (
-        StatusCode::OK,
-        [(header::CONTENT_TYPE, "application/xml; charset=utf-8")],
+    (StatusCode::NOT_MODIFIED, [(header::ETAG, etag.to_string())]).into_response()
+}
+
+fn http_date(dt: DateTime<Utc>) -> String {
+    dt.format("%a, %d %b %Y %H:%M:%S GMT").to_string()
+}
+
+// ── Feeds ────────────────────────────────────
+
+const FEED_LIMIT: i64 = 50;
+
+pub async fn rss_feed(State(state): State<AppState>) -> ApiResult<axum::response::Response> {
+    use axum::http::header;
+    use axum::response::IntoResponse;
+
+    let posts = services::list_blog_posts(&state.db, None, None, FEED_LIMIT, 0).await?;
+    let xml = crate::feeds::rss(&state.config.app_base_url, &posts);
+    Ok((
+        [(header::CONTENT_TYPE, "application/rss+xml; charset=utf-8")],
         xml,
-    ).into_response()
+    )
+        .into_response())
+}
+
+pub async fn atom_feed(State(state): State<AppState>) -> ApiResult<axum::response::Response> {
+    use axum::http::header;
+    use axum::response::IntoResponse;
+
+    let posts = services::list_blog_posts(&state.db, None, None, FEED_LIMIT, 0).await?;
+    let xml = crate::feeds::atom(&state.config.app_base_url, &posts);
+    Ok((
+        [(header::CONTENT_TYPE, "application/atom+xml; charset=utf-8")],
+        xml,
+    )
+        .into_response())
 }
 
 // ── Analytics ────────────────────────────────
@@ -188,9 +652,7 @@ pub async fn track_event(
 
 // ── Experiments ──────────────────────────────
 
-pub async fn list_experiments(
-    State(state): State<AppState>,
-) -> ApiResult<Json<Vec<Experiment>>> {
+pub async fn list_experiments(State(state): State<AppState>) -> ApiResult<Json<Vec<Experiment>>> {
     let exps = services::list_experiments(&state.db).await?;
     Ok(Json(exps))
 }
@@ -210,14 +672,12 @@ fn validate_admin_token(state: &AppState, headers: &HeaderMap) -> ApiResult<()>
         return Err(ApiError::Unauthorized);
     };
 
-    if let Some(auth) = headers.get("authorization") {
-        if let Ok(value) = auth.to_str() {
-            if let Some(token) = value.strip_prefix("Bearer ") {
-                if token == expected {
-                    return Ok(());
-                }
-            }
-        }
+    if let Some(auth) = headers.get("authorization")
+        && let Ok(value) = auth.to_str()
+        && let Some(token) = value.strip_prefix("Bearer ")
+        && token == expected
+    {
+        return Ok(());
     }
 
     Err(ApiError::Unauthorized)
@@ -232,26 +692,892 @@ pub async fn admin_stats(
     Ok(Json(stats))
 }
 
+#[derive(Debug, Deserialize)]
+pub struct ContactListParams {
+    pub limit: Option<i64>,
+    /// `next_cursor` from the previous page.
+    pub cursor: Option<String>,
+    /// Substring match on name, email or company.
+    pub q: Option<String>,
+    pub status: Option<LeadStatus>,
+    pub owner: Option<String>,
+    pub source: Option<String>,
+    /// Normalized crop id (see `/admin/crop-types`).
+    pub crop_type: Option<String>,
+    /// Inclusive start date (UTC).
+    pub from: Option<NaiveDate>,
+    /// Inclusive end date (UTC).
+    pub to: Option<NaiveDate>,
+    pub sort: Option<ContactSort>,
+}
+
+#[derive(Debug, Default, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ContactSort {
+    #[default]
+    Recent,
+    Score,
+}
+
 pub async fn admin_contacts(
     State(state): State<AppState>,
     headers: HeaderMap,
-    Query(params): Query<PaginationParams>,
-) -> ApiResult<Json<Vec<ContactSubmission>>> {
+    Query(params): Query<ContactListParams>,
+) -> ApiResult<Json<Page<ContactSubmission>>> {
     validate_admin_token(&state, &headers)?;
-    let limit = params.limit.unwrap_or(100).min(200);
-    let offset = params.offset.unwrap_or(0);
-    let rows = services::list_contacts(&state.db, limit, offset).await?;
-    Ok(Json(rows))
+    let limit = params.limit.unwrap_or(100).clamp(1, 200);
+    let by_score = params.sort.unwrap_or_default() == ContactSort::Score;
+    let after = parse_cursor(params.cursor.as_deref(), by_score)?;
+    let (from, to) = open_bounds(params.from, params.to)?;
+    let filter = ContactFilter {
+        search: non_blank(params.q),
+        status: params.status.map(|s| s.as_str().to_string()),
+        owner: params.owner,
+        source: params.source,
+        crop_type: params.crop_type,
+        from,
+        to,
+    };
+    let page = services::list_contacts(&state.db, &filter, by_score, after, limit).await?;
+    Ok(Json(page))
+}
+
+fn parse_cursor(cursor: Option<&str>, with_score: bool) -> ApiResult<Option<SubmissionCursor>> {
+    cursor
+        .map(|c| {
+            SubmissionCursor::decode(c, with_score).ok_or_else(|| {
+                ApiError::Validation("Invalid cursor for this sort order".to_string())
+            })
+        })
+        .transpose()
+}
+
+fn non_blank(s: Option<String>) -> Option<String> {
+    s.filter(|s| !s.trim().is_empty())
+}
+
+// ── Admin: Lead Pipeline ─────────────────────
+
+pub async fn admin_contact(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(id): Path<Uuid>,
+) -> ApiResult<Json<ContactDetail>> {
+    validate_admin_token(&state, &headers)?;
+    let contact = services::get_contact(&state.db, id).await?;
+    let detail = services::contact_detail(&state.db, contact).await?;
+    Ok(Json(detail))
+}
+
+pub async fn admin_set_contact_status(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(id): Path<Uuid>,
+    Json(req): Json<LeadStatusRequest>,
+) -> ApiResult<Json<ContactDetail>> {
+    validate_admin_token(&state, &headers)?;
+    req.validate()
+        .map_err(|e| ApiError::Validation(e.to_string()))?;
+    let contact = services::set_contact_status(&state.db, id, &req).await?;
+    tracing::info!("Lead {id} moved to {}", contact.status);
+    let detail = services::contact_detail(&state.db, contact).await?;
+    Ok(Json(detail))
+}
+
+pub async fn admin_set_contact_owner(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(id): Path<Uuid>,
+    Json(req): Json<LeadOwnerRequest>,
+) -> ApiResult<Json<ContactSubmission>> {
+    validate_admin_token(&state, &headers)?;
+    req.validate()
+        .map_err(|e| ApiError::Validation(e.to_string()))?;
+    let contact = services::set_contact_owner(&state.db, id, req.owner.as_deref()).await?;
+    Ok(Json(contact))
+}
+
+pub async fn admin_add_contact_note(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(id): Path<Uuid>,
+    Json(req): Json<ContactNoteRequest>,
+) -> ApiResult<Json<ContactNote>> {
+    validate_admin_token(&state, &headers)?;
+    req.validate()
+        .map_err(|e| ApiError::Validation(e.to_string()))?;
+    let note = services::add_contact_note(&state.db, id, &req).await?;
+    Ok(Json(note))
+}
+
+pub async fn admin_lead_pipeline(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> ApiResult<Json<Vec<PipelineStage>>> {
+    validate_admin_token(&state, &headers)?;
+    let stages = services::lead_pipeline(&state.db).await?;
+    Ok(Json(stages))
+}
+
+// ── Admin: Lead Scoring ──────────────────────
+
+pub async fn admin_scoring_config(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> ApiResult<Json<ScoringConfigResponse>> {
+    validate_admin_token(&state, &headers)?;
+    let config = services::get_scoring_config(&state.db).await?;
+    Ok(Json(config))
+}
+
+/// Replace the scoring model and rescore every contact against it.
+pub async fn admin_update_scoring_config(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(req): Json<ScoringConfigRequest>,
+) -> ApiResult<Json<ScoringConfigResponse>> {
+    validate_admin_token(&state, &headers)?;
+    req.validate()
+        .map_err(|e| ApiError::Validation(e.to_string()))?;
+    req.config.validate().map_err(ApiError::Validation)?;
+    let config = services::save_scoring_config(&state.db, &req).await?;
+    let rescored = services::rescore_contacts(&state.db).await?;
+    tracing::info!("Scoring model updated; rescored {rescored} contacts");
+    Ok(Json(config))
+}
+
+pub async fn admin_rescore_contacts(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> ApiResult<Json<RescoreResult>> {
+    validate_admin_token(&state, &headers)?;
+    let rescored = services::rescore_contacts(&state.db).await?;
+    Ok(Json(RescoreResult { rescored }))
+}
+
+/// The crop vocabulary contacts are normalized onto, for filters and scoring.
+pub async fn admin_crop_types(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> ApiResult<Json<&'static [CropType]>> {
+    validate_admin_token(&state, &headers)?;
+    Ok(Json(CROP_TYPES))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct WaitlistListParams {
+    pub limit: Option<i64>,
+    /// `next_cursor` from the previous page.
+    pub cursor: Option<String>,
+    /// Substring match on name, email or company.
+    pub q: Option<String>,
+    pub interest: Option<String>,
+    /// Only confirmed (`true`) or only pending (`false`) entries.
+    pub confirmed: Option<bool>,
+    pub pilot_status: Option<PilotStatus>,
+    pub cohort_id: Option<Uuid>,
+    /// Inclusive start date (UTC).
+    pub from: Option<NaiveDate>,
+    /// Inclusive end date (UTC).
+    pub to: Option<NaiveDate>,
 }
 
 pub async fn admin_waitlist(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Query(params): Query<WaitlistListParams>,
+) -> ApiResult<Json<Page<WaitlistEntry>>> {
+    validate_admin_token(&state, &headers)?;
+    let limit = params.limit.unwrap_or(100).clamp(1, 200);
+    let after = parse_cursor(params.cursor.as_deref(), false)?;
+    let (from, to) = open_bounds(params.from, params.to)?;
+    let filter = WaitlistFilter {
+        search: non_blank(params.q),
+        interest: params.interest,
+        confirmed: params.confirmed,
+        pilot_status: params.pilot_status.map(|s| s.as_str().to_string()),
+        cohort_id: params.cohort_id,
+        from,
+        to,
+    };
+    let page = services::list_waitlist(&state.db, &filter, after, limit).await?;
+    Ok(Json(page))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct LeaderboardParams {
+    pub interest: Option<String>,
+    pub limit: Option<i64>,
+}
+
+pub async fn admin_referral_leaderboard(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Query(params): Query<LeaderboardParams>,
+) -> ApiResult<Json<Vec<ReferralLeader>>> {
+    validate_admin_token(&state, &headers)?;
+    let limit = params.limit.unwrap_or(25).clamp(1, 200);
+    let leaders =
+        services::referral_leaderboard(&state.db, params.interest.as_deref(), limit).await?;
+    Ok(Json(leaders))
+}
+
+// ── Admin: Pilot Cohorts ─────────────────────
+
+pub async fn admin_cohorts(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> ApiResult<Json<Vec<CohortFill>>> {
+    validate_admin_token(&state, &headers)?;
+    let cohorts = services::cohort_fill_rates(&state.db).await?;
+    Ok(Json(cohorts))
+}
+
+pub async fn admin_create_cohort(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(req): Json<PilotCohortRequest>,
+) -> ApiResult<Json<PilotCohort>> {
+    validate_admin_token(&state, &headers)?;
+    validate_cohort(&req)?;
+    let cohort = services::create_cohort(&state.db, &req).await?;
+    tracing::info!(
+        "Pilot cohort created: {} ({} seats)",
+        cohort.name,
+        cohort.capacity
+    );
+    Ok(Json(cohort))
+}
+
+pub async fn admin_update_cohort(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(id): Path<Uuid>,
+    Json(req): Json<PilotCohortRequest>,
+) -> ApiResult<Json<PilotCohort>> {
+    validate_admin_token(&state, &headers)?;
+    validate_cohort(&req)?;
+    let cohort = services::update_cohort(&state.db, id, &req).await?;
+    Ok(Json(cohort))
+}
+
+fn validate_cohort(req: &PilotCohortRequest) -> ApiResult<()> {
+    req.validate()
+        .map_err(|e| ApiError::Validation(e.to_string()))?;
+    if let Some(crop) = req.crop_focus.as_deref()
+        && !CROP_TYPES.iter().any(|c| c.id == crop)
+    {
+        return Err(ApiError::Validation(format!("Unknown crop type '{crop}'")));
+    }
+    Ok(())
+}
+
+/// Invite, accept, decline or waitlist a pilot entry, emailing the grower
+/// unless `notify` is false.
+pub async fn admin_set_pilot_status(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(id): Path<Uuid>,
+    Json(req): Json<PilotStatusRequest>,
+) -> ApiResult<Json<PilotEntryDetail>> {
+    validate_admin_token(&state, &headers)?;
+    req.validate()
+        .map_err(|e| ApiError::Validation(e.to_string()))?;
+    let (entry, cohort) = services::set_pilot_status(&state.db, id, &req).await?;
+    tracing::info!("Pilot entry {id} moved to {}", entry.pilot_status);
+
+    if req.notify.unwrap_or(true) {
+        let queued = crate::email::send_pilot_status(
+            &state.outbox,
+            &state.db,
+            &state.config,
+            &entry,
+            req.status,
+            cohort.as_ref(),
+        )
+        .await;
+        if let Err(e) = queued {
+            tracing::error!("Failed to queue pilot status email: {e}");
+        }
+    }
+
+    let detail = services::pilot_entry_detail(&state.db, entry, cohort).await?;
+    Ok(Json(detail))
+}
+
+// ── Admin: Privacy ───────────────────────────
+
+/// Erase someone on request through another channel (email, phone).
+pub async fn admin_erase_person(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(req): Json<AdminErasureRequest>,
+) -> ApiResult<Json<DataErasure>> {
+    validate_admin_token(&state, &headers)?;
+    req.validate()
+        .map_err(|e| ApiError::Validation(e.to_string()))?;
+    let erasure = services::erase_personal_data(
+        &state.db,
+        &req.email,
+        ErasureVia::Admin,
+        req.performed_by.as_deref(),
+    )
+    .await?;
+    tracing::info!("Data erasure {} completed (admin)", erasure.id);
+    Ok(Json(erasure))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AdminAccessExportParams {
+    pub email: String,
+    #[serde(default)]
+    pub format: BundleFormat,
+}
+
+/// The same bundle a person gets from their access link, for answering a
+/// request that came in another way.
+pub async fn admin_access_export(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Query(params): Query<AdminAccessExportParams>,
+) -> ApiResult<axum::response::Response> {
+    validate_admin_token(&state, &headers)?;
+    let bundle = services::subject_access_bundle(&state.db, &params.email).await?;
+    tracing::info!("Subject access export downloaded (admin)");
+    bundle_response(&bundle, params.format)
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ErasureListParams {
+    /// Look up erasures of one address (matched by hash).
+    pub email: Option<String>,
+    pub limit: Option<i64>,
+}
+
+pub async fn admin_erasures(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Query(params): Query<ErasureListParams>,
+) -> ApiResult<Json<Vec<DataErasure>>> {
+    validate_admin_token(&state, &headers)?;
+    let limit = params.limit.unwrap_or(50).clamp(1, 200);
+    let erasures = services::list_erasures(&state.db, params.email.as_deref(), limit).await?;
+    Ok(Json(erasures))
+}
+
+// ── Admin: Email ─────────────────────────────
+
+/// Mail held by the in-memory transport (`MAIL_TRANSPORT=memory`), for
+/// checking what the app sends without a real inbox.
+pub async fn admin_captured_email(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> ApiResult<Json<Vec<crate::email::OutgoingEmail>>> {
+    validate_admin_token(&state, &headers)?;
+    let captured = state.mailer.captured().ok_or_else(|| {
+        ApiError::NotFound("Captured email is only kept with MAIL_TRANSPORT=memory".to_string())
+    })?;
+    Ok(Json(captured))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct OutboxParams {
+    /// pending, sent or dead
+    pub status: Option<String>,
+    pub limit: Option<i64>,
+}
+
+pub async fn admin_outbox(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Query(params): Query<OutboxParams>,
+) -> ApiResult<Json<Vec<OutboxEmail>>> {
+    validate_admin_token(&state, &headers)?;
+    if let Some(status) = params.status.as_deref()
+        && !["pending", "sent", "dead"].contains(&status)
+    {
+        return Err(ApiError::Validation(format!(
+            "Unknown status '{status}' (expected pending, sent or dead)"
+        )));
+    }
+    let limit = params.limit.unwrap_or(50).clamp(1, 200);
+    let emails = services::list_outbox(&state.db, params.status.as_deref(), limit).await?;
+    Ok(Json(emails))
+}
+
+pub async fn admin_outbox_stats(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> ApiResult<Json<OutboxStats>> {
+    validate_admin_token(&state, &headers)?;
+    let stats = services::outbox_stats(&state.db).await?;
+    Ok(Json(stats))
+}
+
+/// Deliver due mail now. Called by Cloud Scheduler where the in-process
+/// outbox worker is off (`BACKGROUND_WORKERS=false`).
+pub async fn admin_deliver_outbox(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> ApiResult<Json<OutboxDelivery>> {
+    validate_admin_token(&state, &headers)?;
+    let delivery = state.outbox.deliver_pending(state.mailer.as_ref()).await?;
+    if delivery.attempted > 0 {
+        tracing::info!(
+            "Scheduled outbox run attempted {} email(s)",
+            delivery.attempted
+        );
+    }
+    Ok(Json(delivery))
+}
+
+/// Queue any daily digests that are due. Called by Cloud Scheduler where the
+/// in-process digest loop is off (`BACKGROUND_WORKERS=false`).
+pub async fn admin_send_digests(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> ApiResult<Json<serde_json::Value>> {
+    validate_admin_token(&state, &headers)?;
+    let queued = crate::email::digest::send_due(&state.outbox, &state.db, &state.config).await?;
+    if queued > 0 {
+        tracing::info!("Queued {queued} daily digest(s)");
+    }
+    Ok(Json(serde_json::json!({ "queued": queued })))
+}
+
+pub async fn admin_retry_email(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(id): Path<Uuid>,
+) -> ApiResult<Json<OutboxEmail>> {
+    validate_admin_token(&state, &headers)?;
+    let email = services::retry_email(&state.db, id).await?;
+    state.outbox.wake();
+    tracing::info!("Email {id} queued for retry");
+    Ok(Json(email))
+}
+
+pub async fn admin_auto_reply_config(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> ApiResult<Json<AutoReplyConfigResponse>> {
+    validate_admin_token(&state, &headers)?;
+    let config = services::get_auto_reply_config(&state.db).await?;
+    Ok(Json(config))
+}
+
+pub async fn admin_update_auto_reply_config(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(req): Json<AutoReplyConfigRequest>,
+) -> ApiResult<Json<AutoReplyConfigResponse>> {
+    validate_admin_token(&state, &headers)?;
+    req.validate()
+        .map_err(|e| ApiError::Validation(e.to_string()))?;
+    req.config
+        .validate_templates()
+        .map_err(ApiError::Validation)?;
+    let config = services::save_auto_reply_config(&state.db, &req).await?;
+    tracing::info!("Auto-reply config updated");
+    Ok(Json(config))
+}
+
+pub async fn admin_routing_config(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> ApiResult<Json<RoutingConfigResponse>> {
+    validate_admin_token(&state, &headers)?;
+    let config = services::get_routing_config(&state.db).await?;
+    Ok(Json(config))
+}
+
+pub async fn admin_update_routing_config(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(req): Json<RoutingConfigRequest>,
+) -> ApiResult<Json<RoutingConfigResponse>> {
+    validate_admin_token(&state, &headers)?;
+    req.validate()
+        .map_err(|e| ApiError::Validation(e.to_string()))?;
+    req.config.validate().map_err(ApiError::Validation)?;
+    let config = services::save_routing_config(&state.db, &req).await?;
+    tracing::info!("Notification routing config updated");
+    Ok(Json(config))
+}
+
+// ── Admin: Exports ───────────────────────────
+
+#[derive(Debug, Deserialize)]
+pub struct ExportParams {
+    #[serde(default)]
+    pub format: ExportFormat,
+    /// Inclusive start date (UTC); open-ended when omitted.
+    pub from: Option<NaiveDate>,
+    /// Inclusive end date (UTC); open-ended when omitted.
+    pub to: Option<NaiveDate>,
+    /// Contacts only.
+    pub source: Option<String>,
+    /// Waitlist only.
+    pub interest: Option<String>,
+}
+
+pub async fn admin_export_contacts(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Query(params): Query<ExportParams>,
+) -> ApiResult<axum::response::Response> {
+    validate_admin_token(&state, &headers)?;
+    if params.interest.is_some() {
+        return Err(ApiError::Validation(
+            "interest applies to the waitlist export only".to_string(),
+        ));
+    }
+    let (from, to) = open_bounds(params.from, params.to)?;
+    let rows = services::export_contacts(state.db.clone(), from, to, params.source);
+    export_response("contacts", params.format, rows).await
+}
+
+pub async fn admin_export_waitlist(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Query(params): Query<ExportParams>,
+) -> ApiResult<axum::response::Response> {
+    validate_admin_token(&state, &headers)?;
+    if params.source.is_some() {
+        return Err(ApiError::Validation(
+            "source applies to the contacts export only".to_string(),
+        ));
+    }
+    let (from, to) = open_bounds(params.from, params.to)?;
+    let rows = services::export_waitlist(state.db.clone(), from, to, params.interest);
+    export_response("waitlist", params.format, rows).await
+}
+
+/// CSV streams row by row as the cursor advances. XLSX can only be written
+/// once complete, so it is built from the same stream and sent in one piece.
+async fn export_response<R, S>(
+    name: &str,
+    format: ExportFormat,
+    rows: S,
+) -> ApiResult<axum::response::Response>
+where
+    R: ExportRow + Send + 'static,
+    S: Stream<Item = Result<R, sqlx::Error>> + Send + 'static,
+{
+    use axum::body::{Body, Bytes};
+    use axum::http::header;
+    use axum::response::IntoResponse;
+
+    let body = match format {
+        ExportFormat::Csv => {
+            let header_row = stream::once(async { Ok(Bytes::from(export::csv_header::<R>())) });
+            let label = name.to_string();
+            let data_rows = rows
+                .map_ok(|row| Bytes::from(export::csv_row(&row)))
+                .inspect_err(move |e| tracing::error!("{label} export failed mid-stream: {e}"));
+            Body::from_stream(header_row.chain(data_rows))
+        }
+        ExportFormat::Xlsx => {
+            let xlsx_err = |e: rust_xlsxwriter::XlsxError| ApiError::Internal(e.into());
+            let mut rows = std::pin::pin!(rows);
+            let mut xlsx = XlsxExport::new::<R>().map_err(xlsx_err)?;
+            while let Some(row) = rows.try_next().await? {
+                xlsx.push(&row).map_err(xlsx_err)?;
+            }
+            Body::from(xlsx.finish().map_err(xlsx_err)?)
+        }
+    };
+
+    let filename = format!(
+        "{name}-{}.{}",
+        Utc::now().format("%Y-%m-%d"),
+        format.extension()
+    );
+    Ok((
+        [
+            (header::CONTENT_TYPE, format.content_type().to_string()),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"{filename}\""),
+            ),
+        ],
+        body,
+    )
+        .into_response())
+}
+
+// ── Admin: People ────────────────────────────
+
+#[derive(Debug, Deserialize)]
+pub struct PersonLookupParams {
+    pub email: String,
+    pub limit: Option<i64>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TimelineParams {
+    pub limit: Option<i64>,
+}
+
+pub async fn admin_person_timeline(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(id): Path<Uuid>,
+    Query(params): Query<TimelineParams>,
+) -> ApiResult<Json<PersonTimeline>> {
+    validate_admin_token(&state, &headers)?;
+    let limit = params.limit.unwrap_or(500).min(5000);
+    let person = services::get_person(&state.db, id).await?;
+    let timeline = services::person_timeline(&state.db, person, limit).await?;
+    Ok(Json(timeline))
+}
+
+pub async fn admin_person_lookup(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Query(params): Query<PersonLookupParams>,
+) -> ApiResult<Json<PersonTimeline>> {
+    validate_admin_token(&state, &headers)?;
+    let limit = params.limit.unwrap_or(500).min(5000);
+    let person = services::find_person_by_email(&state.db, &params.email).await?;
+    let timeline = services::person_timeline(&state.db, person, limit).await?;
+    Ok(Json(timeline))
+}
+
+// ── Admin: Blog ──────────────────────────────
+
+pub async fn admin_list_posts(
     State(state): State<AppState>,
     headers: HeaderMap,
     Query(params): Query<PaginationParams>,
-) -> ApiResult<Json<Vec<WaitlistEntry>>> {
+) -> ApiResult<Json<Vec<BlogPost>>> {
     validate_admin_token(&state, &headers)?;
-    let limit = params.limit.unwrap_or(100).min(200);
+    let limit = params.limit.unwrap_or(50).min(200);
     let offset = params.offset.unwrap_or(0);
-    let rows = services::list_waitlist(&state.db, limit, offset).await?;
-    Ok(Json(rows))
+    let posts = services::admin_list_blog_posts(&state.db, limit, offset).await?;
+    Ok(Json(posts))
+}
+
+pub async fn admin_scheduled_posts(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> ApiResult<Json<Vec<BlogPostSummary>>> {
+    validate_admin_token(&state, &headers)?;
+    let posts = services::list_scheduled_blog_posts(&state.db).await?;
+    Ok(Json(posts))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct DateRangeParams {
+    /// Inclusive start date (UTC); defaults to 30 days before `to`.
+    pub from: Option<NaiveDate>,
+    /// Inclusive end date (UTC); defaults to today.
+    pub to: Option<NaiveDate>,
+}
+
+impl DateRangeParams {
+    /// Half-open `[from 00:00, to+1 00:00)` UTC bounds.
+    fn bounds(&self) -> ApiResult<(DateTime<Utc>, DateTime<Utc>)> {
+        let to = self.to.unwrap_or_else(|| Utc::now().date_naive());
+        let from = self.from.unwrap_or(to - chrono::Days::new(30));
+        if from > to {
+            return Err(ApiError::Validation(
+                "from must not be after to".to_string(),
+            ));
+        }
+        let start = from.and_time(NaiveTime::MIN).and_utc();
+        let end = (to + chrono::Days::new(1))
+            .and_time(NaiveTime::MIN)
+            .and_utc();
+        Ok((start, end))
+    }
+}
+
+/// Half-open UTC bounds; `None` leaves that side unbounded.
+type OpenBounds = (Option<DateTime<Utc>>, Option<DateTime<Utc>>);
+
+/// Like `DateRangeParams::bounds`, but either end may be left open.
+fn open_bounds(from: Option<NaiveDate>, to: Option<NaiveDate>) -> ApiResult<OpenBounds> {
+    if let (Some(from), Some(to)) = (from, to)
+        && from > to
+    {
+        return Err(ApiError::Validation(
+            "from must not be after to".to_string(),
+        ));
+    }
+    let start = from.map(|d| d.and_time(NaiveTime::MIN).and_utc());
+    let end = to.map(|d| {
+        (d + chrono::Days::new(1))
+            .and_time(NaiveTime::MIN)
+            .and_utc()
+    });
+    Ok((start, end))
+}
+
+pub async fn admin_blog_metrics(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Query(params): Query<DateRangeParams>,
+) -> ApiResult<Json<Vec<BlogPostMetrics>>> {
+    validate_admin_token(&state, &headers)?;
+    let (from, to) = params.bounds()?;
+    let metrics = services::blog_post_metrics(&state.db, from, to).await?;
+    Ok(Json(metrics))
+}
+
+pub async fn admin_get_post(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(id): Path<Uuid>,
+) -> ApiResult<Json<BlogPostDetail>> {
+    validate_admin_token(&state, &headers)?;
+    let post = services::get_blog_post(&state.db, id).await?;
+    let detail = services::blog_post_detail(&state.db, post).await?;
+    Ok(Json(detail))
+}
+
+pub async fn admin_create_post(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(req): Json<BlogPostRequest>,
+) -> ApiResult<Json<BlogPostDetail>> {
+    validate_admin_token(&state, &headers)?;
+    req.validate()
+        .map_err(|e| ApiError::Validation(e.to_string()))?;
+    let post = services::create_blog_post(&state.db, &req).await?;
+    tracing::info!("Blog post created: {}", post.slug);
+    let detail = services::blog_post_detail(&state.db, post).await?;
+    Ok(Json(detail))
+}
+
+pub async fn admin_update_post(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(id): Path<Uuid>,
+    Json(req): Json<BlogPostRequest>,
+) -> ApiResult<Json<BlogPostDetail>> {
+    validate_admin_token(&state, &headers)?;
+    req.validate()
+        .map_err(|e| ApiError::Validation(e.to_string()))?;
+    let post = services::update_blog_post(&state.db, id, &req).await?;
+    tracing::info!("Blog post updated: {}", post.slug);
+    let detail = services::blog_post_detail(&state.db, post).await?;
+    Ok(Json(detail))
+}
+
+pub async fn admin_publish_post(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(id): Path<Uuid>,
+) -> ApiResult<Json<BlogPostDetail>> {
+    validate_admin_token(&state, &headers)?;
+    let post = services::set_blog_post_published(&state.db, id, true).await?;
+    let detail = services::blog_post_detail(&state.db, post).await?;
+    Ok(Json(detail))
+}
+
+pub async fn admin_unpublish_post(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(id): Path<Uuid>,
+) -> ApiResult<Json<BlogPostDetail>> {
+    validate_admin_token(&state, &headers)?;
+    let post = services::set_blog_post_published(&state.db, id, false).await?;
+    let detail = services::blog_post_detail(&state.db, post).await?;
+    Ok(Json(detail))
+}
+
+pub async fn admin_delete_post(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(id): Path<Uuid>,
+) -> ApiResult<Json<serde_json::Value>> {
+    validate_admin_token(&state, &headers)?;
+    services::delete_blog_post(&state.db, id).await?;
+    tracing::info!("Blog post deleted: {id}");
+    Ok(Json(serde_json::json!({ "success": true })))
+}
+
+pub async fn admin_deleted_posts(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> ApiResult<Json<Vec<DeletedBlogPost>>> {
+    validate_admin_token(&state, &headers)?;
+    let posts = services::list_deleted_blog_posts(&state.db).await?;
+    Ok(Json(posts))
+}
+
+pub async fn admin_deleted_post_revisions(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(id): Path<Uuid>,
+) -> ApiResult<Json<Vec<BlogPostRevisionSummary>>> {
+    validate_admin_token(&state, &headers)?;
+    let revisions = services::list_deleted_blog_post_revisions(&state.db, id).await?;
+    Ok(Json(revisions))
+}
+
+pub async fn admin_restore_deleted_post(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path((id, revision)): Path<(Uuid, i32)>,
+) -> ApiResult<Json<BlogPostDetail>> {
+    validate_admin_token(&state, &headers)?;
+    let post = services::restore_deleted_blog_post(&state.db, id, revision).await?;
+    tracing::info!(
+        "Deleted blog post {} restored from revision {revision}",
+        post.slug
+    );
+    let detail = services::blog_post_detail(&state.db, post).await?;
+    Ok(Json(detail))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RevisionDiffParams {
+    pub from: i32,
+    pub to: i32,
+}
+
+pub async fn admin_post_revisions(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(id): Path<Uuid>,
+) -> ApiResult<Json<Vec<BlogPostRevisionSummary>>> {
+    validate_admin_token(&state, &headers)?;
+    let revisions = services::list_blog_post_revisions(&state.db, id).await?;
+    Ok(Json(revisions))
+}
+
+pub async fn admin_post_revision(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path((id, revision)): Path<(Uuid, i32)>,
+) -> ApiResult<Json<BlogPostRevision>> {
+    validate_admin_token(&state, &headers)?;
+    let rev = services::get_blog_post_revision(&state.db, id, revision).await?;
+    Ok(Json(rev))
+}
+
+pub async fn admin_post_revision_diff(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(id): Path<Uuid>,
+    Query(params): Query<RevisionDiffParams>,
+) -> ApiResult<Json<BlogRevisionDiff>> {
+    validate_admin_token(&state, &headers)?;
+    let from = services::get_blog_post_revision(&state.db, id, params.from).await?;
+    let to = services::get_blog_post_revision(&state.db, id, params.to).await?;
+    Ok(Json(crate::content::diff_revisions(id, &from, &to)))
+}
+
+pub async fn admin_restore_post_revision(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path((id, revision)): Path<(Uuid, i32)>,
+) -> ApiResult<Json<BlogPostDetail>> {
+    validate_admin_token(&state, &headers)?;
+    let post = services::restore_blog_post_revision(&state.db, id, revision).await?;
+    tracing::info!("Blog post {} restored to revision {revision}", post.slug);
+    let detail = services::blog_post_detail(&state.db, post).await?;
+    Ok(Json(detail))
 }
@@ -1,4 +1,4 @@
-use axum::{routing::{get, post}, Router};
+use axum::{routing::{get, post, put}, Router};
 
 use super::handlers;
 use crate::AppState;
@@ -7,10 +7,23 @@ pub fn api_router() -> Router<AppState> {
     Router::new()
         .route("/health", get(handlers::health_check))
         .route("/blog", get(handlers::list_posts))
+        .route("/blog/categories", get(handlers::list_categories))
+        .route("/blog/tags", get(handlers::list_tags))
+        .route("/blog/search", get(handlers::search_posts))
+        .route("/blog/rss.xml", get(handlers::rss_feed))
+        .route("/blog/atom.xml", get(handlers::atom_feed))
         .route("/blog/{slug}", get(handlers::get_post))
         .route("/contact", post(handlers::submit_contact))
         .route("/waitlist", post(handlers::submit_waitlist))
+        .route("/waitlist/confirm", post(handlers::confirm_waitlist))
+        .route("/waitlist/status/{code}", get(handlers::waitlist_status))
+        .route("/privacy/unsubscribe", post(handlers::unsubscribe))
+        .route("/privacy/erasure-request", post(handlers::request_erasure))
+        .route("/privacy/erase", post(handlers::confirm_erasure))
+        .route("/privacy/access-request", post(handlers::request_access))
+        .route("/privacy/export", get(handlers::access_export))
         .route("/sitemap.xml", get(handlers::dynamic_sitemap))
+        .route("/sitemaps/{file}", get(handlers::sitemap_page))
         // Analytics
         .route("/analytics/pageview", post(handlers::track_pageview))
         .route("/analytics/event", post(handlers::track_event))
@@ -19,5 +32,67 @@ pub fn api_router() -> Router<AppState> {
         // Admin
         .route("/admin/stats", get(handlers::admin_stats))
         .route("/admin/contacts", get(handlers::admin_contacts))
+        .route("/admin/contacts/export", get(handlers::admin_export_contacts))
+        .route("/admin/contacts/pipeline", get(handlers::admin_lead_pipeline))
+        .route("/admin/contacts/rescore", post(handlers::admin_rescore_contacts))
+        .route("/admin/contacts/{id}", get(handlers::admin_contact))
+        .route("/admin/contacts/{id}/status", post(handlers::admin_set_contact_status))
+        .route("/admin/contacts/{id}/owner", put(handlers::admin_set_contact_owner))
+        .route("/admin/contacts/{id}/notes", post(handlers::admin_add_contact_note))
+        .route(
+            "/admin/scoring",
+            get(handlers::admin_scoring_config).put(handlers::admin_update_scoring_config),
+        )
+        .route(
+            "/admin/auto-replies",
+            get(handlers::admin_auto_reply_config).put(handlers::admin_update_auto_reply_config),
+        )
+        .route(
+            "/admin/notification-routing",
+            get(handlers::admin_routing_config).put(handlers::admin_update_routing_config),
+        )
+        .route("/admin/crop-types", get(handlers::admin_crop_types))
         .route("/admin/waitlist", get(handlers::admin_waitlist))
+        .route("/admin/waitlist/export", get(handlers::admin_export_waitlist))
+        .route("/admin/waitlist/referrals", get(handlers::admin_referral_leaderboard))
+        .route("/admin/waitlist/{id}/pilot-status", post(handlers::admin_set_pilot_status))
+        .route("/admin/cohorts", get(handlers::admin_cohorts).post(handlers::admin_create_cohort))
+        .route("/admin/cohorts/{id}", put(handlers::admin_update_cohort))
+        .route("/admin/people", get(handlers::admin_person_lookup))
+        .route(
+            "/admin/privacy/erasures",
+            get(handlers::admin_erasures).post(handlers::admin_erase_person),
+        )
+        .route("/admin/privacy/export", get(handlers::admin_access_export))
+        .route("/admin/email/captured", get(handlers::admin_captured_email))
+        .route("/admin/email/outbox", get(handlers::admin_outbox))
+        .route("/admin/email/outbox/stats", get(handlers::admin_outbox_stats))
+        .route("/admin/email/outbox/deliver", post(handlers::admin_deliver_outbox))
+        .route("/admin/email/digest/send", post(handlers::admin_send_digests))
+        .route("/admin/email/outbox/{id}/retry", post(handlers::admin_retry_email))
+        .route("/admin/people/{id}", get(handlers::admin_person_timeline))
+        .route("/admin/blog", get(handlers::admin_list_posts).post(handlers::admin_create_post))
+        .route("/admin/blog/scheduled", get(handlers::admin_scheduled_posts))
+        .route("/admin/blog/metrics", get(handlers::admin_blog_metrics))
+        .route("/admin/blog/deleted", get(handlers::admin_deleted_posts))
+        .route("/admin/blog/deleted/{id}/revisions", get(handlers::admin_deleted_post_revisions))
+        .route(
+            "/admin/blog/deleted/{id}/revisions/{revision}/restore",
+            post(handlers::admin_restore_deleted_post),
+        )
+        .route(
+            "/admin/blog/{id}",
+            get(handlers::admin_get_post)
+                .put(handlers::admin_update_post)
+                .delete(handlers::admin_delete_post),
+        )
+        .route("/admin/blog/{id}/publish", post(handlers::admin_publish_post))
+        .route("/admin/blog/{id}/unpublish", post(handlers::admin_unpublish_post))
+        .route("/admin/blog/{id}/revisions", get(handlers::admin_post_revisions))
+        .route("/admin/blog/{id}/revisions/diff", get(handlers::admin_post_revision_diff))
+        .route("/admin/blog/{id}/revisions/{revision}", get(handlers::admin_post_revision))
+        .route(
+            "/admin/blog/{id}/revisions/{revision}/restore",
+            post(handlers::admin_restore_post_revision),
+        )
 }
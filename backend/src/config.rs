@@ -1,5 +1,19 @@
+use std::path::PathBuf;
+
 use anyhow::{Context, Result};
 
+/// Where outgoing email goes, from `MAIL_TRANSPORT`.
+#[derive(Clone, Debug)]
+pub enum MailTransport {
+    Smtp,
+    /// One `.eml` file per message in `MAIL_DIR`.
+    File(PathBuf),
+    Stdout,
+    /// Kept in memory and listed by `GET /admin/email/captured`.
+    Memory,
+    Disabled,
+}
+
 #[derive(Clone, Debug)]
 pub struct AppConfig {
     pub database_url: String,
@@ -13,34 +27,101 @@ pub struct AppConfig {
     pub smtp_username: Option<String>,
     pub smtp_password: Option<String>,
     pub admin_email: Option<String>,
+    pub mail_transport: MailTransport,
+    pub mail_from: String,
     pub app_base_url: String,
+    /// HMAC key for links emailed to users (confirmation, unsubscribe).
+    pub token_secret: String,
+    /// Deliver queued mail and send digests from in-process loops. Turned off
+    /// with `BACKGROUND_WORKERS=false` where CPU is only allocated during
+    /// requests (Cloud Run), and a scheduler calls `POST /admin/email/outbox/deliver`
+    /// and `POST /admin/email/digest/send` instead.
+    pub background_workers: bool,
 }
 
 impl AppConfig {
     pub fn from_env() -> Result<Self> {
+        let explicit_environment = std::env::var("ENVIRONMENT").ok().filter(|s| !s.is_empty());
+        // Emailed links are only forgeable with a known key, so the built-in
+        // one is reserved for a deployment that says it's a development box
+        let token_secret = match std::env::var("TOKEN_SECRET").ok().filter(|s| !s.is_empty()) {
+            Some(secret) => secret,
+            None if explicit_environment.as_deref() == Some("development") => {
+                tracing::warn!(
+                    "TOKEN_SECRET is not set; signing emailed links with a development key"
+                );
+                "dev-token-secret".to_string()
+            }
+            None => anyhow::bail!("TOKEN_SECRET must be set unless ENVIRONMENT=development"),
+        };
+        let environment = explicit_environment.unwrap_or_else(|| "development".to_string());
+
+        let smtp_host = std::env::var("SMTP_HOST").ok().filter(|s| !s.is_empty());
+        let smtp_username = std::env::var("SMTP_USERNAME")
+            .ok()
+            .filter(|s| !s.is_empty());
+        // Without an explicit choice, send over SMTP when it's configured and
+        // otherwise print mail in development and drop it in production
+        let mail_transport = match std::env::var("MAIL_TRANSPORT")
+            .ok()
+            .filter(|s| !s.is_empty())
+            .as_deref()
+        {
+            Some("smtp") => MailTransport::Smtp,
+            Some("file") => MailTransport::File(
+                std::env::var("MAIL_DIR")
+                    .unwrap_or_else(|_| "mail".to_string())
+                    .into(),
+            ),
+            Some("stdout") => MailTransport::Stdout,
+            Some("memory") => MailTransport::Memory,
+            Some("none") => MailTransport::Disabled,
+            Some(other) => anyhow::bail!(
+                "Unknown MAIL_TRANSPORT '{other}' (expected smtp, file, stdout, memory or none)"
+            ),
+            None if smtp_host.is_some() => MailTransport::Smtp,
+            None if environment == "production" => MailTransport::Disabled,
+            None => MailTransport::Stdout,
+        };
+        let mail_from = std::env::var("MAIL_FROM")
+            .ok()
+            .filter(|s| !s.is_empty())
+            .unwrap_or_else(|| {
+                format!(
+                    "Terroir AI <{}>",
+                    smtp_username.as_deref().unwrap_or("noreply@terroirai.com")
+                )
+            });
+
         Ok(Self {
-            database_url: std::env::var("DATABASE_URL")
-                .context("DATABASE_URL must be set")?,
+            database_url: std::env::var("DATABASE_URL").context("DATABASE_URL must be set")?,
             port: std::env::var("PORT")
                 .unwrap_or_else(|_| "8080".to_string())
                 .parse()
                 .context("PORT must be a valid u16")?,
-            cors_origin: std::env::var("CORS_ORIGIN")
-                .unwrap_or_else(|_| "*".to_string()),
+            cors_origin: std::env::var("CORS_ORIGIN").unwrap_or_else(|_| "*".to_string()),
             db_max_connections: std::env::var("DB_MAX_CONNECTIONS")
                 .unwrap_or_else(|_| "5".to_string())
                 .parse()
                 .unwrap_or(5),
-            environment: std::env::var("ENVIRONMENT")
-                .unwrap_or_else(|_| "development".to_string()),
+            environment,
             admin_token: std::env::var("ADMIN_TOKEN").ok().filter(|s| !s.is_empty()),
-            smtp_host: std::env::var("SMTP_HOST").ok().filter(|s| !s.is_empty()),
+            smtp_host,
             smtp_port: std::env::var("SMTP_PORT").ok().and_then(|p| p.parse().ok()),
-            smtp_username: std::env::var("SMTP_USERNAME").ok().filter(|s| !s.is_empty()),
-            smtp_password: std::env::var("SMTP_PASSWORD").ok().filter(|s| !s.is_empty()),
+            smtp_username,
+            smtp_password: std::env::var("SMTP_PASSWORD")
+                .ok()
+                .filter(|s| !s.is_empty()),
             admin_email: std::env::var("ADMIN_EMAIL").ok().filter(|s| !s.is_empty()),
+            mail_transport,
+            mail_from,
             app_base_url: std::env::var("APP_BASE_URL")
                 .unwrap_or_else(|_| "http://localhost:4200".to_string()),
+            token_secret,
+            background_workers: !matches!(
+                std::env::var("BACKGROUND_WORKERS").as_deref(),
+                Ok("false" | "0")
+            ),
         })
     }
 
@@ -0,0 +1,341 @@
+// Blog content pipeline: Markdown → HTML → allowlist sanitizer, plus the
+// plain-text derived fields (excerpt, read time) shown on the blog index.
+
+use std::sync::LazyLock;
+
+use pulldown_cmark::{Options, Parser};
+use similar::TextDiff;
+use uuid::Uuid;
+
+use crate::models::{BlogPostRequest, BlogPostRevision, BlogRevisionDiff, FieldChange};
+
+const WORDS_PER_MINUTE: usize = 200;
+const EXCERPT_MAX_CHARS: usize = 280;
+
+static SANITIZER: LazyLock<ammonia::Builder<'static>> = LazyLock::new(|| {
+    let mut builder = ammonia::Builder::default();
+    builder
+        .link_rel(Some("noopener noreferrer"))
+        .url_schemes(["http", "https", "mailto"].into())
+        .add_tag_attributes("code", ["class"]);
+    builder
+});
+
+/// Fields derived from the post body on every save.
+pub struct RenderedContent {
+    pub content_html: String,
+    pub excerpt: String,
+    pub read_time_minutes: i32,
+}
+
+/// Render a post request into stored HTML. Markdown wins over raw HTML when both
+/// are sent; either way the result goes through the sanitizer. An explicit
+/// excerpt or read time from the editor overrides the computed one.
+pub fn render_post(req: &BlogPostRequest) -> RenderedContent {
+    let content_html = match (&req.content_markdown, &req.content_html) {
+        (Some(md), _) => sanitize_html(&render_markdown(md)),
+        (None, Some(html)) => sanitize_html(html),
+        (None, None) => String::new(),
+    };
+    let text = plain_text(&content_html);
+
+    let excerpt = req
+        .excerpt
+        .as_deref()
+        .map(str::trim)
+        .filter(|e| !e.is_empty())
+        .map(str::to_string)
+        .unwrap_or_else(|| excerpt(&text));
+    let read_time_minutes = req
+        .read_time_minutes
+        .unwrap_or_else(|| read_time_minutes(&text));
+
+    RenderedContent {
+        content_html,
+        excerpt,
+        read_time_minutes,
+    }
+}
+
+pub fn render_markdown(markdown: &str) -> String {
+    let options =
+        Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH | Options::ENABLE_FOOTNOTES;
+    let mut html = String::with_capacity(markdown.len() * 3 / 2);
+    pulldown_cmark::html::push_html(&mut html, Parser::new_ext(markdown, options));
+    html
+}
+
+/// Strip anything not on the allowlist: scripts, inline event handlers,
+/// `javascript:` URLs, styles, iframes.
+pub fn sanitize_html(html: &str) -> String {
+    SANITIZER.clean(html).to_string()
+}
+
+/// Make untrusted text safe to drop into Markdown: every ASCII punctuation
+/// character is backslash-escaped and line breaks become spaces, so it renders
+/// as the literal text rather than links, images, raw HTML or new blocks.
+pub fn escape_markdown(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len() + text.len() / 4);
+    for c in text.chars() {
+        match c {
+            '\r' | '\n' => escaped.push(' '),
+            c if c.is_ascii_punctuation() => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Visible text of an HTML fragment with whitespace collapsed. Block-level tags
+/// become word breaks so paragraphs don't run together.
+pub fn plain_text(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut chars = html.chars();
+    while let Some(c) = chars.next() {
+        if c != '<' {
+            text.push(c);
+            continue;
+        }
+        let tag: String = chars.by_ref().take_while(|&c| c != '>').collect();
+        let name = tag
+            .trim_start_matches('/')
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or("")
+            .to_ascii_lowercase();
+        if is_block_tag(&name) {
+            text.push(' ');
+        }
+    }
+
+    let text = decode_entities(&text);
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+pub fn excerpt(text: &str) -> String {
+    if text.chars().count() <= EXCERPT_MAX_CHARS {
+        return text.to_string();
+    }
+    let cut: String = text.chars().take(EXCERPT_MAX_CHARS).collect();
+    let cut = match cut.rfind(' ') {
+        Some(i) => &cut[..i],
+        None => &cut,
+    };
+    format!(
+        "{}…",
+        cut.trim_end_matches(|c: char| c.is_ascii_punctuation())
+    )
+}
+
+pub fn read_time_minutes(text: &str) -> i32 {
+    let words = text.split_whitespace().count();
+    words.div_ceil(WORDS_PER_MINUTE).max(1) as i32
+}
+
+/// URL-safe form of a tag or category name: "H-2A Labor" → "h-2a-labor".
+pub fn slugify(name: &str) -> String {
+    name.to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+/// Compare two revisions of the same post: metadata fields that changed plus a
+/// unified line diff of the body.
+pub fn diff_revisions(
+    post_id: Uuid,
+    from: &BlogPostRevision,
+    to: &BlogPostRevision,
+) -> BlogRevisionDiff {
+    let fields = [
+        ("slug", Some(from.slug.clone()), Some(to.slug.clone())),
+        ("title", Some(from.title.clone()), Some(to.title.clone())),
+        ("subtitle", from.subtitle.clone(), to.subtitle.clone()),
+        (
+            "category",
+            Some(from.category.clone()),
+            Some(to.category.clone()),
+        ),
+        (
+            "hero_image_url",
+            from.hero_image_url.clone(),
+            to.hero_image_url.clone(),
+        ),
+        (
+            "excerpt",
+            Some(from.excerpt.clone()),
+            Some(to.excerpt.clone()),
+        ),
+        (
+            "author_name",
+            Some(from.author_name.clone()),
+            Some(to.author_name.clone()),
+        ),
+        (
+            "read_time_minutes",
+            Some(from.read_time_minutes.to_string()),
+            Some(to.read_time_minutes.to_string()),
+        ),
+        (
+            "tags",
+            from.tags.as_ref().map(|t| t.join(", ")),
+            to.tags.as_ref().map(|t| t.join(", ")),
+        ),
+        (
+            "published",
+            Some(from.published.to_string()),
+            Some(to.published.to_string()),
+        ),
+        (
+            "published_at",
+            from.published_at.map(|d| d.to_rfc3339()),
+            to.published_at.map(|d| d.to_rfc3339()),
+        ),
+    ];
+    let changed_fields = fields
+        .into_iter()
+        .filter(|(_, a, b)| a != b)
+        .map(|(field, from, to)| FieldChange { field, from, to })
+        .collect();
+
+    let (old, new) = match (&from.content_markdown, &to.content_markdown) {
+        (Some(a), Some(b)) => (a.as_str(), b.as_str()),
+        _ => (from.content_html.as_str(), to.content_html.as_str()),
+    };
+    let content_diff = TextDiff::from_lines(old, new)
+        .unified_diff()
+        .context_radius(3)
+        .header(
+            &format!("revision {}", from.revision_number),
+            &format!("revision {}", to.revision_number),
+        )
+        .to_string();
+
+    BlogRevisionDiff {
+        post_id,
+        from_revision: from.revision_number,
+        to_revision: to.revision_number,
+        changed_fields,
+        content_diff,
+    }
+}
+
+fn is_block_tag(name: &str) -> bool {
+    matches!(
+        name,
+        "p" | "br"
+            | "hr"
+            | "div"
+            | "li"
+            | "ul"
+            | "ol"
+            | "blockquote"
+            | "pre"
+            | "table"
+            | "tr"
+            | "td"
+            | "th"
+            | "h1"
+            | "h2"
+            | "h3"
+            | "h4"
+            | "h5"
+            | "h6"
+    )
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&nbsp;", " ")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sanitizer_strips_scripts_handlers_and_javascript_urls() {
+        let html = sanitize_html(concat!(
+            r#"<p onclick="steal()">Hi<script>alert(1)</script></p>"#,
+            r#"<img src="x.png" onerror="steal()">"#,
+            r#"<a href="javascript:alert(1)">bad</a>"#,
+            r#"<a href="https://example.com">good</a>"#,
+        ));
+        assert!(!html.contains("script"), "{html}");
+        assert!(
+            !html.contains("onclick") && !html.contains("onerror"),
+            "{html}"
+        );
+        assert!(!html.contains("javascript:"), "{html}");
+        assert!(html.contains(r#"href="https://example.com""#), "{html}");
+        assert!(html.contains(r#"rel="noopener noreferrer""#), "{html}");
+    }
+
+    #[test]
+    fn markdown_is_sanitized_too() {
+        let markdown = "[x](javascript:alert(1))\n\n<script>alert(1)</script>";
+        let html = sanitize_html(&render_markdown(markdown));
+        assert!(
+            !html.contains("javascript:") && !html.contains("<script"),
+            "{html}"
+        );
+    }
+
+    #[test]
+    fn escaped_markdown_renders_as_literal_text() {
+        let escaped = escape_markdown("[Claim](https://evil.example)\n# *now*");
+        let html = render_markdown(&escaped);
+        assert!(
+            !html.contains("<a") && !html.contains("<h1") && !html.contains("<em"),
+            "{html}"
+        );
+        assert_eq!(plain_text(&html), "[Claim](https://evil.example) # *now*");
+    }
+
+    #[test]
+    fn plain_text_keeps_words_apart_and_decodes_entities() {
+        let html = concat!(
+            "<h2>Labor</h2><p>Crews &amp; <em>costs</em>&nbsp;&lt;2026&gt;</p>",
+            "<ul><li>one</li><li>two</li></ul>",
+        );
+        assert_eq!(plain_text(html), "Labor Crews & costs <2026> one two");
+    }
+
+    #[test]
+    fn short_text_is_its_own_excerpt() {
+        assert_eq!(excerpt("A short post."), "A short post.");
+    }
+
+    #[test]
+    fn excerpt_cuts_multibyte_text_at_a_word_boundary() {
+        let text = "Über ñandú café — ".repeat(30);
+        let cut = excerpt(&text);
+        assert!(cut.ends_with('…'), "{cut}");
+        assert!(cut.chars().count() <= EXCERPT_MAX_CHARS + 1);
+        let words = cut.trim_end_matches('…');
+        assert!(text.starts_with(words));
+        assert!(text[words.len()..].starts_with(' '), "cut mid-word: {cut}");
+    }
+
+    #[test]
+    fn read_time_rounds_up_with_a_minimum_of_one() {
+        assert_eq!(read_time_minutes(""), 1);
+        assert_eq!(read_time_minutes(&"word ".repeat(200)), 1);
+        assert_eq!(read_time_minutes(&"word ".repeat(201)), 2);
+        assert_eq!(read_time_minutes(&"word ".repeat(1000)), 5);
+    }
+
+    #[test]
+    fn slugify_keeps_letters_and_digits() {
+        assert_eq!(slugify("H-2A Labor"), "h-2a-labor");
+        assert_eq!(slugify("  Ag Tech & AI!  "), "ag-tech-ai");
+    }
+}
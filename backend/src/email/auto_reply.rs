@@ -0,0 +1,368 @@
+// Acknowledgements sent to the people who fill in our forms: contact form
+// submitters right away, waitlist signups once they confirm their address.
+// The wording lives in `AutoReplyConfig`, which admins can replace at runtime,
+// with a reply per contact `source` and per waitlist `interest`. Subjects and
+// bodies are templates over the submission's fields; bodies are Markdown.
+
+use std::collections::HashMap;
+
+use minijinja::{Environment, UndefinedBehavior, Value, context};
+use serde::{Deserialize, Serialize};
+
+use super::{OutgoingEmail, templates, unsubscribe_url};
+use crate::config::AppConfig;
+use crate::content;
+use crate::models::{ContactSubmission, EmailAbout, WaitlistEntry};
+use crate::normalize;
+use crate::services;
+
+const CONTACT_KIND: &str = "contact_auto_reply";
+const WAITLIST_KIND: &str = "waitlist_auto_reply";
+/// At most one contact acknowledgement per address in this window, so the
+/// form can't be used to flood someone's inbox.
+const CONTACT_REPLY_WINDOW_HOURS: i32 = 24;
+/// Longest a submitter's value may run in a reply; enough for a name or a
+/// farm, not for a message.
+const MAX_FIELD_CHARS: usize = 60;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AutoReplyConfig {
+    /// Replies to contact form submissions, by `source`.
+    pub contact: AutoReplyRules,
+    /// Replies to confirmed waitlist signups, by `interest`.
+    pub waitlist: AutoReplyRules,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AutoReplyRules {
+    /// Used for any source or interest without its own entry; `None` sends
+    /// nothing for those.
+    pub default: Option<AutoReply>,
+    #[serde(default)]
+    pub overrides: HashMap<String, AutoReply>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AutoReply {
+    /// An override with `enabled: false` turns replies off for that key.
+    #[serde(default = "enabled_by_default")]
+    pub enabled: bool,
+    pub subject: String,
+    /// Markdown. Both the subject and body can use the fields listed in
+    /// `CONTACT_FIELDS` / `WAITLIST_FIELDS`, e.g. `{{ first_name }}`.
+    pub body: String,
+}
+
+fn enabled_by_default() -> bool {
+    true
+}
+
+/// Template fields for contact replies; missing values are empty strings.
+const CONTACT_FIELDS: &[&str] = &["first_name", "name", "company", "crop", "acreage", "source"];
+/// Template fields for waitlist replies; missing values are empty strings.
+const WAITLIST_FIELDS: &[&str] = &["first_name", "name", "company", "interest", "referral_url"];
+/// Fields we fill in ourselves. The rest come from the submitter; they go
+/// through `plain_field` everywhere and are Markdown-escaped in the HTML part.
+const TRUSTED_FIELDS: &[&str] = &["referral_url"];
+
+impl AutoReplyRules {
+    fn for_key(&self, key: &str) -> Option<&AutoReply> {
+        self.overrides
+            .get(key)
+            .or(self.default.as_ref())
+            .filter(|reply| reply.enabled)
+    }
+}
+
+impl AutoReplyConfig {
+    /// Check every template parses and renders against sample values, so a
+    /// typo is caught when the config is saved rather than when a grower
+    /// submits the form.
+    pub fn validate_templates(&self) -> Result<(), String> {
+        let sets = [
+            ("contact", &self.contact, sample_context(CONTACT_FIELDS)),
+            ("waitlist", &self.waitlist, sample_context(WAITLIST_FIELDS)),
+        ];
+        for (form, rules, ctx) in sets {
+            let replies = rules.default.iter().map(|reply| ("default", reply)).chain(
+                rules
+                    .overrides
+                    .iter()
+                    .map(|(key, reply)| (key.as_str(), reply)),
+            );
+            for (key, reply) in replies {
+                for (part, source) in [("subject", &reply.subject), ("body", &reply.body)] {
+                    if source.trim().is_empty() {
+                        return Err(format!("{form}.{key}: {part} is empty"));
+                    }
+                    render_str(source, &ctx).map_err(|e| format!("{form}.{key}: {part}: {e}"))?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Default for AutoReplyConfig {
+    fn default() -> Self {
+        Self {
+            contact: AutoReplyRules {
+                default: Some(AutoReply {
+                    enabled: true,
+                    subject: "We got your message".to_string(),
+                    body: DEFAULT_CONTACT_BODY.to_string(),
+                }),
+                overrides: HashMap::new(),
+            },
+            waitlist: AutoReplyRules {
+                default: Some(AutoReply {
+                    enabled: true,
+                    subject: "You're on the Terroir AI list".to_string(),
+                    body: DEFAULT_WAITLIST_BODY.to_string(),
+                }),
+                overrides: HashMap::from([(
+                    "pilot_2026".to_string(),
+                    AutoReply {
+                        enabled: true,
+                        subject: "What happens next with the 2026 pilot".to_string(),
+                        body: DEFAULT_PILOT_BODY.to_string(),
+                    },
+                )]),
+            },
+        }
+    }
+}
+
+const DEFAULT_CONTACT_BODY: &str = "\
+Hi {{ first_name or \"there\" }},
+
+Thanks for getting in touch. This is a quick note to say your message reached \
+us, so there's no need to send it again.
+
+Someone from our team will reply within two business days\
+{% if crop %}, and we'll make sure it's someone who knows {{ crop | lower }}{% endif %}.
+
+— The Terroir AI team";
+
+const DEFAULT_WAITLIST_BODY: &str = "\
+Hi {{ first_name or \"there\" }},
+
+Your email is confirmed and you're on the Terroir AI list. We'll write when \
+there's something worth your time: new features, field results and early \
+access openings.
+
+— The Terroir AI team";
+
+const DEFAULT_PILOT_BODY: &str = "\
+Hi {{ first_name or \"there\" }},
+
+Your spot on the 2026 pilot list is confirmed. Here's what happens next:
+
+1. **We group growers into cohorts** by region and crop so each one gets \
+agronomy support that fits their operation.
+2. **Seats are limited.** When a cohort that fits you opens, we'll email you \
+an invitation with the details.
+3. **Accept your seat** by replying to that email, and we'll schedule onboarding.
+
+Know another grower who should be in the pilot? Send them your link: \
+{{ referral_url }}
+
+— The Terroir AI team";
+
+/// Acknowledge a contact form submission, unless the sender unsubscribed or
+/// already got one recently.
+pub async fn reply_to_contact(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    config: &AppConfig,
+    submission: &ContactSubmission,
+) -> anyhow::Result<()> {
+    let rules = services::get_auto_reply_config(&mut **tx).await?.config;
+    let Some(reply) = rules.contact.for_key(&submission.source) else {
+        return Ok(());
+    };
+    if !services::contact_auto_reply_allowed(
+        &mut **tx,
+        &submission.email,
+        CONTACT_KIND,
+        CONTACT_REPLY_WINDOW_HOURS,
+    )
+    .await?
+    {
+        tracing::debug!("Skipping contact auto-reply for {}", submission.id);
+        return Ok(());
+    }
+    let crop = submission
+        .crop_type_normalized
+        .as_deref()
+        .filter(|crop| *crop != "other")
+        .map(normalize::crop_label);
+    let fields = HashMap::from([
+        ("first_name", first_name(Some(&submission.name))),
+        ("name", submission.name.as_str()),
+        ("company", submission.company.as_deref().unwrap_or_default()),
+        ("crop", crop.unwrap_or_default()),
+        ("acreage", submission.acreage.as_deref().unwrap_or_default()),
+        ("source", submission.source.as_str()),
+    ]);
+    let about = EmailAbout::Contact(submission.id);
+    send(
+        tx,
+        config,
+        CONTACT_KIND,
+        &submission.email,
+        reply,
+        fields,
+        about,
+    )
+    .await
+}
+
+/// Welcome a newly confirmed waitlist signup. Confirming is an explicit
+/// opt-in, so there's no unsubscribe or frequency check.
+pub async fn reply_to_waitlist(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    config: &AppConfig,
+    entry: &WaitlistEntry,
+) -> anyhow::Result<()> {
+    let rules = services::get_auto_reply_config(&mut **tx).await?.config;
+    let Some(reply) = rules.waitlist.for_key(&entry.interest) else {
+        return Ok(());
+    };
+    let referral_url = format!(
+        "{}/?ref={}&utm_source=referral&utm_medium=email&utm_campaign={}",
+        config.app_base_url.trim_end_matches('/'),
+        entry.referral_code,
+        entry.interest,
+    );
+    let fields = HashMap::from([
+        ("first_name", first_name(entry.name.as_deref())),
+        ("name", entry.name.as_deref().unwrap_or_default()),
+        ("company", entry.company.as_deref().unwrap_or_default()),
+        ("interest", entry.interest.as_str()),
+        ("referral_url", referral_url.as_str()),
+    ]);
+    let about = EmailAbout::WaitlistEntry(entry.id);
+    send(
+        tx,
+        config,
+        WAITLIST_KIND,
+        &entry.email,
+        reply,
+        fields,
+        about,
+    )
+    .await
+}
+
+async fn send(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    config: &AppConfig,
+    kind: &str,
+    to: &str,
+    reply: &AutoReply,
+    fields: HashMap<&str, &str>,
+    about: EmailAbout,
+) -> anyhow::Result<()> {
+    // Anyone can type anyone's address into the form, so the submitter's
+    // values are trimmed down to something that can't carry a pitch or a link
+    // before they go into a mail we send in our own name
+    let plain: HashMap<&str, String> = fields
+        .iter()
+        .map(|(&field, &value)| {
+            let value = if TRUSTED_FIELDS.contains(&field) {
+                value.to_string()
+            } else {
+                plain_field(value)
+            };
+            (field, value)
+        })
+        .collect();
+    let ctx = Value::from_serialize(&plain);
+    let subject = render_str(&reply.subject, &ctx)?;
+    let body = render_str(&reply.body, &ctx)?;
+    // The HTML part goes through Markdown, where a name like `*Urgent*` would
+    // still be formatted, so it's rendered again with those values escaped
+    let escaped: HashMap<&str, String> = plain
+        .iter()
+        .map(|(&field, value)| {
+            let value = if TRUSTED_FIELDS.contains(&field) {
+                value.clone()
+            } else {
+                content::escape_markdown(value)
+            };
+            (field, value)
+        })
+        .collect();
+    let markdown = render_str(&reply.body, &Value::from_serialize(&escaped))?;
+    let unsubscribe_url = unsubscribe_url(config, to);
+    let rendered = templates::render(
+        "auto_reply",
+        context! {
+            subject,
+            body,
+            body_html => content::sanitize_html(&content::render_markdown(&markdown)),
+            unsubscribe_url,
+        },
+    )?;
+    let email = OutgoingEmail {
+        to: to.to_string(),
+        subject,
+        text: rendered.text,
+        html: Some(rendered.html),
+        unsubscribe_url: Some(unsubscribe_url),
+    };
+    services::enqueue_email(&mut **tx, kind, &email, Some(about)).await?;
+    Ok(())
+}
+
+/// Render an admin-written template. Unknown fields are errors rather than
+/// blanks so typos surface on save.
+fn render_str(source: &str, ctx: &Value) -> Result<String, minijinja::Error> {
+    let mut env = Environment::new();
+    env.set_undefined_behavior(UndefinedBehavior::Strict);
+    env.render_str(source, ctx)
+}
+
+fn sample_context(fields: &[&str]) -> Value {
+    let sample: HashMap<_, _> = fields.iter().map(|field| (*field, "sample")).collect();
+    Value::from_serialize(&sample)
+}
+
+/// A submitter's value as it may appear in a reply: one line, no angle
+/// brackets, no words that a mail client would turn into a link, and at most
+/// `MAX_FIELD_CHARS` long.
+fn plain_field(value: &str) -> String {
+    let words: Vec<String> = value
+        .split_whitespace()
+        .map(|word| word.replace(['<', '>'], ""))
+        .filter(|word| !word.is_empty() && !looks_like_link(word))
+        .collect();
+    let mut plain = String::new();
+    for word in words {
+        let len = plain.chars().count() + usize::from(!plain.is_empty()) + word.chars().count();
+        if len > MAX_FIELD_CHARS {
+            break;
+        }
+        if !plain.is_empty() {
+            plain.push(' ');
+        }
+        plain.push_str(&word);
+    }
+    plain
+}
+
+/// URLs, email addresses and bare domains like `example.com`.
+fn looks_like_link(word: &str) -> bool {
+    let word = word.trim_end_matches(|c: char| c.is_ascii_punctuation());
+    word.contains("://")
+        || word.contains('@')
+        || word.to_ascii_lowercase().starts_with("www.")
+        || word.rsplit_once('.').is_some_and(|(host, tld)| {
+            !host.is_empty() && tld.len() >= 2 && tld.chars().all(char::is_alphabetic)
+        })
+}
+
+fn first_name(name: Option<&str>) -> &str {
+    name.and_then(|name| name.split_whitespace().next())
+        .unwrap_or_default()
+}
@@ -0,0 +1,226 @@
+// Daily digests for routes with `delivery: digest`. Notifications for those
+// recipients are held in `notification_digest_items`; once a day, at the
+// configured hour, each recipient gets one email listing everything held for
+// them since, grouped by the route that sent it their way. `send_due` is run
+// by a loop here or, with background workers off, by a scheduler through the
+// admin API; either way a run before the digest hour finds nothing to send.
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::{DateTime, NaiveTime, Utc};
+use minijinja::context;
+use serde::Serialize;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use super::{Outbox, OutgoingEmail, admin_url, templates};
+use crate::config::AppConfig;
+use crate::models::{ContactSubmission, DigestBatch, WaitlistEntry};
+use crate::normalize;
+use crate::services;
+
+const KIND: &str = "daily_digest";
+const CHECK_INTERVAL: Duration = Duration::from_secs(60);
+
+pub fn spawn_worker(outbox: Outbox, db: PgPool, config: Arc<AppConfig>) {
+    tokio::spawn(async move {
+        loop {
+            match send_due(&outbox, &db, &config).await {
+                Ok(0) => {}
+                Ok(n) => tracing::info!("Queued {n} daily digest(s)"),
+                Err(e) => tracing::warn!("Daily digest failed: {e:#}"),
+            }
+            tokio::time::sleep(CHECK_INTERVAL).await;
+        }
+    });
+}
+
+/// Queue a digest for every recipient with items held from before the most
+/// recent digest hour. Items and digests are written in one transaction, so a
+/// failure leaves the items for the next check.
+pub async fn send_due(outbox: &Outbox, db: &PgPool, config: &AppConfig) -> anyhow::Result<usize> {
+    let routing = services::get_routing_config(db).await?.config;
+    let cutoff = last_digest_time(Utc::now(), routing.digest_hour_utc);
+
+    let mut tx = db.begin().await?;
+    let batch = services::claim_digest_batch(&mut tx, cutoff).await?;
+    if batch.items.is_empty() {
+        return Ok(0);
+    }
+    let digests = render_digests(&batch, config, cutoff)?;
+    for (email, item_ids) in &digests {
+        let email_id = services::enqueue_email(&mut *tx, KIND, email, None).await?;
+        services::link_digest_items(&mut *tx, email_id, item_ids).await?;
+    }
+    tx.commit().await?;
+    outbox.wake();
+    Ok(digests.len())
+}
+
+/// The latest time at or before `now` that falls on `hour` UTC.
+fn last_digest_time(now: DateTime<Utc>, hour: u32) -> DateTime<Utc> {
+    let time = NaiveTime::from_hms_opt(hour.min(23), 0, 0).unwrap_or_default();
+    let today = now.date_naive().and_time(time).and_utc();
+    if today <= now {
+        today
+    } else {
+        today - chrono::Duration::days(1)
+    }
+}
+
+#[derive(Default, Serialize)]
+struct Section<'a> {
+    route: &'a str,
+    contacts: Vec<ContactLine<'a>>,
+    signups: Vec<SignupLine<'a>>,
+}
+
+#[derive(Serialize)]
+struct ContactLine<'a> {
+    name: &'a str,
+    email: &'a str,
+    /// Company, crop, acreage, source, score and time, joined with " · ".
+    details: String,
+    message: String,
+}
+
+#[derive(Serialize)]
+struct SignupLine<'a> {
+    email: &'a str,
+    name: Option<&'a str>,
+    /// Company, interest and time, joined with " · ".
+    details: String,
+}
+
+/// One email per recipient, with the ids of the items it covers.
+fn render_digests(
+    batch: &DigestBatch,
+    config: &AppConfig,
+    cutoff: DateTime<Utc>,
+) -> anyhow::Result<Vec<(OutgoingEmail, Vec<Uuid>)>> {
+    // recipient → route → section, both in alphabetical order
+    let mut by_recipient: BTreeMap<&str, BTreeMap<&str, Section>> = BTreeMap::new();
+    let mut item_ids: HashMap<&str, Vec<Uuid>> = HashMap::new();
+    for item in &batch.items {
+        item_ids
+            .entry(item.recipient.as_str())
+            .or_default()
+            .push(item.id);
+        let section = by_recipient
+            .entry(item.recipient.as_str())
+            .or_default()
+            .entry(item.route.as_str())
+            .or_insert_with(|| Section {
+                route: &item.route,
+                ..Default::default()
+            });
+        // A submission erased since it was held is simply left out
+        if let Some(contact) = item
+            .contact_id
+            .and_then(|id| batch.contacts.iter().find(|c| c.id == id))
+        {
+            section.contacts.push(contact_line(contact));
+        }
+        if let Some(entry) = item
+            .waitlist_entry_id
+            .and_then(|id| batch.waitlist_entries.iter().find(|e| e.id == id))
+        {
+            section.signups.push(signup_line(entry));
+        }
+    }
+
+    let mut digests = Vec::new();
+    for (recipient, sections) in by_recipient {
+        let sections: Vec<Section> = sections
+            .into_values()
+            .filter(|s| !s.contacts.is_empty() || !s.signups.is_empty())
+            .collect();
+        let contacts: usize = sections.iter().map(|s| s.contacts.len()).sum();
+        let signups: usize = sections.iter().map(|s| s.signups.len()).sum();
+        if contacts + signups == 0 {
+            continue;
+        }
+        let counts = [
+            (contacts, "contact", "contacts"),
+            (signups, "signup", "signups"),
+        ]
+        .into_iter()
+        .filter(|(n, _, _)| *n > 0)
+        .map(|(n, one, many)| format!("{n} {}", if n == 1 { one } else { many }))
+        .collect::<Vec<_>>()
+        .join(", ");
+        let subject = format!(
+            "Terroir AI daily digest for {}: {counts}",
+            cutoff.format("%b %-d")
+        );
+        let body = templates::render(
+            "daily_digest",
+            context! {
+                subject,
+                counts,
+                sections,
+                admin_url => admin_url(config),
+            },
+        )?;
+        let email = OutgoingEmail {
+            to: recipient.to_string(),
+            subject,
+            text: body.text,
+            html: Some(body.html),
+            unsubscribe_url: None,
+        };
+        digests.push((email, item_ids.remove(recipient).unwrap_or_default()));
+    }
+    Ok(digests)
+}
+
+fn contact_line(contact: &ContactSubmission) -> ContactLine<'_> {
+    let message = match contact.message.char_indices().nth(280) {
+        Some((end, _)) => format!("{}…", contact.message[..end].trim_end()),
+        None => contact.message.clone(),
+    };
+    let details = [
+        contact.company.clone(),
+        contact
+            .crop_type_normalized
+            .as_deref()
+            .map(|crop| normalize::crop_label(crop).to_string()),
+        contact.acreage.clone(),
+        Some(format!("via {}", contact.source)),
+        contact.score.map(|score| format!("score {score}")),
+        Some(received(contact.created_at)),
+    ];
+    ContactLine {
+        name: &contact.name,
+        email: &contact.email,
+        details: details
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>()
+            .join(" · "),
+        message,
+    }
+}
+
+fn signup_line(entry: &WaitlistEntry) -> SignupLine<'_> {
+    let details = [
+        entry.company.clone(),
+        Some(entry.interest.clone()),
+        Some(received(entry.created_at)),
+    ];
+    SignupLine {
+        email: &entry.email,
+        name: entry.name.as_deref(),
+        details: details
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>()
+            .join(" · "),
+    }
+}
+
+fn received(at: DateTime<Utc>) -> String {
+    at.format("%b %-d %H:%M UTC").to_string()
+}
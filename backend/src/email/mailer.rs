@@ -0,0 +1,199 @@
+// Outgoing mail transports. Production sends over SMTP; in development and
+// tests messages can be written to `.eml` files, printed to stdout, or kept in
+// memory so they can be inspected through the admin API.
+
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+use anyhow::Context;
+use futures_util::future::BoxFuture;
+use lettre::message::header::{ContentType, HeaderName, HeaderValue};
+use lettre::message::{Mailbox, MultiPart};
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::{AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
+use serde::Serialize;
+
+use crate::config::{AppConfig, MailTransport};
+
+/// The in-memory transport keeps this many of the most recent messages.
+const CAPTURE_LIMIT: usize = 200;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct OutgoingEmail {
+    pub to: String,
+    pub subject: String,
+    pub text: String,
+    pub html: Option<String>,
+    /// Sent as a `List-Unsubscribe` header on mail to people (not the admin).
+    pub unsubscribe_url: Option<String>,
+}
+
+pub trait Mailer: Send + Sync {
+    fn send<'a>(&'a self, email: &'a OutgoingEmail) -> BoxFuture<'a, anyhow::Result<()>>;
+
+    /// Messages held by the in-memory transport, oldest first; `None` for
+    /// transports that don't keep what they send.
+    fn captured(&self) -> Option<Vec<OutgoingEmail>> {
+        None
+    }
+}
+
+pub fn from_config(config: &AppConfig) -> anyhow::Result<Arc<dyn Mailer>> {
+    let from: Mailbox = config.mail_from.parse()?;
+    Ok(match &config.mail_transport {
+        MailTransport::Smtp => {
+            let host = config
+                .smtp_host
+                .as_deref()
+                .ok_or_else(|| anyhow::anyhow!("MAIL_TRANSPORT=smtp needs SMTP_HOST"))?;
+            let mut builder = AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(host)?;
+            if let Some(port) = config.smtp_port {
+                builder = builder.port(port);
+            }
+            if let (Some(username), Some(password)) = (&config.smtp_username, &config.smtp_password)
+            {
+                builder = builder.credentials(Credentials::new(username.clone(), password.clone()));
+            }
+            Arc::new(SmtpMailer {
+                transport: builder.build(),
+                from,
+            })
+        }
+        MailTransport::File(dir) => {
+            std::fs::create_dir_all(dir)?;
+            Arc::new(FileMailer {
+                dir: dir.clone(),
+                from,
+            })
+        }
+        MailTransport::Stdout => Arc::new(StdoutMailer),
+        MailTransport::Memory => Arc::new(MemoryMailer::default()),
+        MailTransport::Disabled => Arc::new(DisabledMailer),
+    })
+}
+
+/// Whether sending again can't help: the server rejected the recipient or the
+/// message itself (550–554), or we couldn't build the message at all. Other
+/// failures, including bad SMTP credentials, are worth retrying.
+pub fn is_permanent(err: &anyhow::Error) -> bool {
+    if let Some(e) = err.downcast_ref::<lettre::transport::smtp::Error>() {
+        return e.is_permanent()
+            && e.status()
+                .is_some_and(|code| (550..=554).contains(&u16::from(code)));
+    }
+    err.is::<lettre::address::AddressError>() || err.is::<lettre::error::Error>()
+}
+
+fn build_message(from: &Mailbox, email: &OutgoingEmail) -> anyhow::Result<Message> {
+    let mut builder = Message::builder()
+        .from(from.clone())
+        .to(email
+            .to
+            .parse()
+            .with_context(|| format!("Invalid recipient address '{}'", email.to))?)
+        .subject(&email.subject);
+    if let Some(url) = &email.unsubscribe_url {
+        builder = builder.raw_header(HeaderValue::new(
+            HeaderName::new_from_ascii_str("List-Unsubscribe"),
+            format!("<{url}>"),
+        ));
+    }
+    let message = match &email.html {
+        Some(html) => builder.multipart(MultiPart::alternative_plain_html(
+            email.text.clone(),
+            html.clone(),
+        ))?,
+        None => builder
+            .header(ContentType::TEXT_PLAIN)
+            .body(email.text.clone())?,
+    };
+    Ok(message)
+}
+
+struct SmtpMailer {
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+    from: Mailbox,
+}
+
+impl Mailer for SmtpMailer {
+    fn send<'a>(&'a self, email: &'a OutgoingEmail) -> BoxFuture<'a, anyhow::Result<()>> {
+        Box::pin(async move {
+            let message = build_message(&self.from, email)?;
+            self.transport.send(message).await?;
+            tracing::info!("Email sent: {}", email.subject);
+            Ok(())
+        })
+    }
+}
+
+/// Writes each message as an RFC 5322 `.eml` file, which any mail client can
+/// open to check the rendering.
+struct FileMailer {
+    dir: PathBuf,
+    from: Mailbox,
+}
+
+impl Mailer for FileMailer {
+    fn send<'a>(&'a self, email: &'a OutgoingEmail) -> BoxFuture<'a, anyhow::Result<()>> {
+        Box::pin(async move {
+            let message = build_message(&self.from, email)?;
+            let name = format!(
+                "{}-{}.eml",
+                chrono::Utc::now().format("%Y%m%dT%H%M%S%.3f"),
+                uuid::Uuid::new_v4().simple()
+            );
+            let path = self.dir.join(name);
+            tokio::fs::write(&path, message.formatted()).await?;
+            tracing::info!("Email written to {}: {}", path.display(), email.subject);
+            Ok(())
+        })
+    }
+}
+
+/// Prints the text part only; use the file transport to see the HTML.
+struct StdoutMailer;
+
+impl Mailer for StdoutMailer {
+    fn send<'a>(&'a self, email: &'a OutgoingEmail) -> BoxFuture<'a, anyhow::Result<()>> {
+        Box::pin(async move {
+            println!(
+                "──── Email ────\nTo: {}\nSubject: {}\n\n{}\n───────────────",
+                email.to, email.subject, email.text
+            );
+            Ok(())
+        })
+    }
+}
+
+#[derive(Default)]
+struct MemoryMailer {
+    sent: Mutex<Vec<OutgoingEmail>>,
+}
+
+impl Mailer for MemoryMailer {
+    fn send<'a>(&'a self, email: &'a OutgoingEmail) -> BoxFuture<'a, anyhow::Result<()>> {
+        Box::pin(async move {
+            let mut sent = self.sent.lock().unwrap_or_else(|e| e.into_inner());
+            if sent.len() >= CAPTURE_LIMIT {
+                sent.remove(0);
+            }
+            sent.push(email.clone());
+            Ok(())
+        })
+    }
+
+    fn captured(&self) -> Option<Vec<OutgoingEmail>> {
+        Some(self.sent.lock().unwrap_or_else(|e| e.into_inner()).clone())
+    }
+}
+
+struct DisabledMailer;
+
+impl Mailer for DisabledMailer {
+    fn send<'a>(&'a self, email: &'a OutgoingEmail) -> BoxFuture<'a, anyhow::Result<()>> {
+        Box::pin(async move {
+            tracing::debug!("Mail transport disabled, skipping email: {}", email.subject);
+            Ok(())
+        })
+    }
+}
@@ -0,0 +1,326 @@
+// Notification emails: what we send and to whom. Bodies are rendered from the
+// templates in `templates` and queued in the outbox, whose worker delivers
+// them through whichever `Mailer` transport the config selects. Mail a
+// submission triggers is queued in the submission's own transaction, so the
+// caller wakes the outbox once it commits.
+
+pub mod auto_reply;
+pub mod digest;
+pub mod mailer;
+pub mod outbox;
+pub mod routing;
+mod templates;
+
+use minijinja::{Value, context};
+use sqlx::PgPool;
+
+use crate::config::AppConfig;
+use crate::models::{ContactSubmission, EmailAbout, PilotCohort, PilotStatus, WaitlistEntry};
+use crate::normalize;
+use crate::services;
+use crate::tokens::{self, Purpose};
+
+pub use mailer::{Mailer, OutgoingEmail};
+pub use outbox::Outbox;
+
+/// Tell the team about a contact form submission, as routed by the
+/// notification routing config.
+pub async fn notify_contact(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    config: &AppConfig,
+    submission: &ContactSubmission,
+) -> anyhow::Result<()> {
+    let routing = services::get_routing_config(&mut **tx).await?.config;
+    let recipients = routing.contact_recipients(submission, config.admin_email.as_deref());
+    let subject = format!("New contact: {} ({})", submission.name, submission.email);
+    let fields = vec![
+        ("Name", submission.name.clone()),
+        ("Email", submission.email.clone()),
+        (
+            "Company",
+            submission
+                .company
+                .clone()
+                .unwrap_or_else(|| "—".to_string()),
+        ),
+        (
+            "Phone",
+            submission.phone.clone().unwrap_or_else(|| "—".to_string()),
+        ),
+        (
+            "Acreage",
+            submission
+                .acreage
+                .clone()
+                .unwrap_or_else(|| "—".to_string()),
+        ),
+        (
+            "Crop Type",
+            submission
+                .crop_type
+                .clone()
+                .unwrap_or_else(|| "—".to_string()),
+        ),
+        ("Source", submission.source.clone()),
+        (
+            "Score",
+            match (submission.score, &submission.score_explanation) {
+                (Some(score), Some(why)) => format!("{score} ({why})"),
+                _ => "—".to_string(),
+            },
+        ),
+        (
+            "Date",
+            submission
+                .created_at
+                .format("%Y-%m-%d %H:%M UTC")
+                .to_string(),
+        ),
+    ];
+    let ctx = context! {
+        fields,
+        message => submission.message,
+        admin_url => admin_url(config),
+    };
+    let about = EmailAbout::Contact(submission.id);
+    notify_team(
+        tx,
+        &recipients,
+        &subject,
+        "contact_notification",
+        ctx,
+        about,
+    )
+    .await
+}
+
+pub async fn notify_waitlist(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    config: &AppConfig,
+    entry: &WaitlistEntry,
+) -> anyhow::Result<()> {
+    let routing = services::get_routing_config(&mut **tx).await?.config;
+    let recipients = routing.waitlist_recipients(entry, config.admin_email.as_deref());
+    let subject = format!("New pilot signup: {}", entry.email);
+    let fields = vec![
+        ("Email", entry.email.clone()),
+        (
+            "Name",
+            entry.name.clone().unwrap_or_else(|| "—".to_string()),
+        ),
+        (
+            "Company",
+            entry.company.clone().unwrap_or_else(|| "—".to_string()),
+        ),
+        ("Interest", entry.interest.clone()),
+        (
+            "Date",
+            entry.created_at.format("%Y-%m-%d %H:%M UTC").to_string(),
+        ),
+    ];
+    let ctx = context! { fields, admin_url => admin_url(config) };
+    let about = EmailAbout::WaitlistEntry(entry.id);
+    notify_team(
+        tx,
+        &recipients,
+        &subject,
+        "waitlist_notification",
+        ctx,
+        about,
+    )
+    .await
+}
+
+/// Double opt-in email to the subscriber themselves.
+pub async fn send_waitlist_confirmation(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    config: &AppConfig,
+    entry: &WaitlistEntry,
+    link: &str,
+) -> anyhow::Result<()> {
+    let ctx = context! {
+        name => entry.name,
+        link,
+        days => Purpose::WaitlistConfirm.ttl().num_days(),
+    };
+    let subject = "Confirm your spot on the Terroir AI list";
+    let about = Some(EmailAbout::WaitlistEntry(entry.id));
+    let unsubscribe = Some(unsubscribe_url(config, &entry.email));
+    let email = render_email(
+        &entry.email,
+        subject,
+        "waitlist_confirmation",
+        ctx,
+        unsubscribe,
+    )?;
+    services::enqueue_email(&mut **tx, "waitlist_confirmation", &email, about).await?;
+    Ok(())
+}
+
+/// Tell a grower their pilot status changed. Returns without sending for
+/// statuses that have no template (pending) and to growers who unsubscribed.
+pub async fn send_pilot_status(
+    outbox: &Outbox,
+    db: &PgPool,
+    config: &AppConfig,
+    entry: &WaitlistEntry,
+    status: PilotStatus,
+    cohort: Option<&PilotCohort>,
+) -> anyhow::Result<()> {
+    let cohort_name = cohort.map_or("the Terroir AI pilot", |c| c.name.as_str());
+    let subject = match status {
+        PilotStatus::Pending => return Ok(()),
+        _ if services::is_unsubscribed(db, &entry.email).await? => {
+            tracing::debug!("Skipping pilot status email for {}: unsubscribed", entry.id);
+            return Ok(());
+        }
+        PilotStatus::Invited => format!("You're invited to {cohort_name}"),
+        PilotStatus::Accepted => format!("Welcome to {cohort_name}"),
+        PilotStatus::Declined => format!("Your {cohort_name} seat"),
+        PilotStatus::Waitlisted => format!("You're on the waitlist for {cohort_name}"),
+    };
+    let mut details = Vec::new();
+    if let Some(c) = cohort {
+        if let Some(region) = &c.region {
+            details.push(("Region", region.clone()));
+        }
+        if let Some(crop) = &c.crop_focus {
+            details.push(("Crop focus", normalize::crop_label(crop).to_string()));
+        }
+        if let Some(starts_on) = c.starts_on {
+            details.push(("Starts", starts_on.format("%B %-d, %Y").to_string()));
+        }
+    }
+    let ctx = context! {
+        name => entry.name,
+        status => status.as_str(),
+        cohort_name,
+        details,
+    };
+    let about = Some(EmailAbout::WaitlistEntry(entry.id));
+    let unsubscribe = Some(unsubscribe_url(config, &entry.email));
+    send_email(
+        outbox,
+        &entry.email,
+        &subject,
+        "pilot_status",
+        ctx,
+        about,
+        unsubscribe,
+    )
+    .await
+}
+
+/// Link to confirm a "delete my data" request, sent to the address itself so
+/// only its owner can trigger the erasure.
+pub async fn send_erasure_link(outbox: &Outbox, email: &str, link: &str) -> anyhow::Result<()> {
+    let ctx = context! { link, hours => Purpose::DataErasure.ttl().num_hours() };
+    send_email(
+        outbox,
+        email,
+        "Confirm deletion of your Terroir AI data",
+        "erasure_link",
+        ctx,
+        None,
+        None,
+    )
+    .await
+}
+
+/// Link to download a copy of everything held about the address.
+pub async fn send_access_link(outbox: &Outbox, email: &str, link: &str) -> anyhow::Result<()> {
+    let ctx = context! { link, hours => Purpose::DataAccess.ttl().num_hours() };
+    send_email(
+        outbox,
+        email,
+        "Your Terroir AI data export",
+        "access_link",
+        ctx,
+        None,
+        None,
+    )
+    .await
+}
+
+/// Signed one-click unsubscribe link for emails we send to people.
+fn unsubscribe_url(config: &AppConfig, email: &str) -> String {
+    let token = tokens::sign(
+        &config.token_secret,
+        Purpose::Unsubscribe,
+        &email.trim().to_lowercase(),
+    );
+    format!(
+        "{}/unsubscribe?token={token}",
+        config.app_base_url.trim_end_matches('/')
+    )
+}
+
+fn admin_url(config: &AppConfig) -> String {
+    format!("{}/admin", config.app_base_url)
+}
+
+/// Email the recipients who want each submission as it arrives, and hold it
+/// for the daily digest of the rest.
+async fn notify_team(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    recipients: &[routing::Recipient],
+    subject: &str,
+    template: &str,
+    ctx: Value,
+    about: EmailAbout,
+) -> anyhow::Result<()> {
+    if recipients.is_empty() {
+        tracing::debug!(
+            "No notification recipients (ADMIN_EMAIL not configured), skipping notification"
+        );
+    }
+    for recipient in recipients {
+        match recipient.delivery {
+            routing::Delivery::Immediate => {
+                let email = render_email(&recipient.address, subject, template, ctx.clone(), None)?;
+                services::enqueue_email(&mut **tx, template, &email, Some(about)).await?;
+            }
+            routing::Delivery::Digest => {
+                services::add_digest_item(&mut **tx, &recipient.address, &recipient.route, about)
+                    .await?
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Render `template` and queue the result; the template name doubles as the
+/// outbox `kind`.
+async fn send_email(
+    outbox: &Outbox,
+    to: &str,
+    subject: &str,
+    template: &str,
+    ctx: Value,
+    about: Option<EmailAbout>,
+    unsubscribe_url: Option<String>,
+) -> anyhow::Result<()> {
+    let email = render_email(to, subject, template, ctx, unsubscribe_url)?;
+    outbox.enqueue(template, &email, about).await?;
+    Ok(())
+}
+
+fn render_email(
+    to: &str,
+    subject: &str,
+    template: &str,
+    ctx: Value,
+    unsubscribe_url: Option<String>,
+) -> anyhow::Result<OutgoingEmail> {
+    let body = templates::render(
+        template,
+        context! { subject, unsubscribe_url => unsubscribe_url.as_deref(), ..ctx },
+    )?;
+    Ok(OutgoingEmail {
+        to: to.to_string(),
+        subject: subject.to_string(),
+        text: body.text,
+        html: Some(body.html),
+        unsubscribe_url,
+    })
+}
@@ -0,0 +1,169 @@
+// Delivery side of the email outbox. Requests only write messages to
+// `email_outbox`; this worker sends them through the configured `Mailer`,
+// retrying failures with exponential backoff. A message the server rejects
+// outright, or that fails `MAX_ATTEMPTS` times, is marked dead and waits for an
+// admin to retry it, until it's purged along with old sent mail.
+//
+// Where a background task can't be relied on to run (Cloud Run only allocates
+// CPU during requests), the worker is off and a scheduler calls
+// `deliver_pending` through the admin API instead.
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use sqlx::PgPool;
+use tokio::sync::Notify;
+
+use super::mailer::{self, Mailer, OutgoingEmail};
+use crate::errors::ApiResult;
+use crate::models::{EmailAbout, OutboxDelivery, QueuedEmail};
+use crate::services;
+
+const MAX_ATTEMPTS: i32 = 10;
+const BATCH_SIZE: i64 = 20;
+/// How often to look for due retries when nothing new has been queued.
+const POLL_INTERVAL: Duration = Duration::from_secs(15);
+/// How long a claimed message stays hidden from other workers.
+const LEASE: Duration = Duration::from_secs(300);
+const FIRST_RETRY: chrono::Duration = chrono::Duration::minutes(1);
+const MAX_RETRY: chrono::Duration = chrono::Duration::hours(6);
+const PURGE_INTERVAL: Duration = Duration::from_secs(3600);
+/// How long sent and dead messages are kept for admins to look at.
+const RETENTION_DAYS: i32 = 30;
+/// How long one `deliver_pending` call keeps claiming batches, well inside
+/// Cloud Run's 30s request timeout.
+const RUN_BUDGET: Duration = Duration::from_secs(20);
+
+/// Handle for queueing mail. Cheap to clone; wakes the worker on every
+/// enqueue so new mail goes out right away rather than at the next poll.
+#[derive(Clone)]
+pub struct Outbox {
+    db: PgPool,
+    wake: Arc<Notify>,
+}
+
+impl Outbox {
+    pub fn new(db: PgPool) -> Self {
+        Self {
+            db,
+            wake: Arc::new(Notify::new()),
+        }
+    }
+
+    pub async fn enqueue(
+        &self,
+        kind: &str,
+        email: &OutgoingEmail,
+        about: Option<EmailAbout>,
+    ) -> ApiResult<()> {
+        services::enqueue_email(&self.db, kind, email, about).await?;
+        self.wake();
+        Ok(())
+    }
+
+    /// Have the worker look for due mail now instead of at the next poll.
+    pub fn wake(&self) {
+        self.wake.notify_one();
+    }
+
+    pub fn spawn_worker(&self, mailer: Arc<dyn Mailer>) {
+        tokio::spawn(run(self.clone(), mailer));
+    }
+
+    /// One pass of the worker's job, for a scheduler: purge old finished mail,
+    /// then deliver due messages batch by batch until none are left or
+    /// `RUN_BUDGET` is spent. Safe to overlap with the worker or another
+    /// call, since claimed messages are leased.
+    pub async fn deliver_pending(&self, mailer: &dyn Mailer) -> ApiResult<OutboxDelivery> {
+        let started = Instant::now();
+        let purged = purge(&self.db).await?;
+        let mut attempted = 0;
+        while started.elapsed() < RUN_BUDGET {
+            let n = deliver_due(&self.db, mailer).await?;
+            attempted += n;
+            if (n as i64) < BATCH_SIZE {
+                break;
+            }
+        }
+        Ok(OutboxDelivery { attempted, purged })
+    }
+}
+
+async fn run(outbox: Outbox, mailer: Arc<dyn Mailer>) {
+    let mut last_purge: Option<Instant> = None;
+    loop {
+        if last_purge.is_none_or(|at| at.elapsed() >= PURGE_INTERVAL) {
+            if let Err(e) = purge(&outbox.db).await {
+                tracing::warn!("Failed to purge old emails: {e}");
+            }
+            last_purge = Some(Instant::now());
+        }
+
+        match deliver_due(&outbox.db, mailer.as_ref()).await {
+            // A full batch probably means more are waiting
+            Ok(n) if n as i64 == BATCH_SIZE => continue,
+            Ok(_) => {}
+            Err(e) => tracing::warn!("Email outbox delivery failed: {e}"),
+        }
+
+        tokio::select! {
+            _ = outbox.wake.notified() => {}
+            _ = tokio::time::sleep(POLL_INTERVAL) => {}
+        }
+    }
+}
+
+async fn purge(db: &PgPool) -> ApiResult<u64> {
+    let purged = services::purge_finished_emails(db, RETENTION_DAYS).await?;
+    if purged > 0 {
+        tracing::info!("Purged {purged} sent or dead emails older than {RETENTION_DAYS} days");
+    }
+    Ok(purged)
+}
+
+async fn deliver_due(db: &PgPool, mailer: &dyn Mailer) -> ApiResult<usize> {
+    let due = services::claim_due_emails(db, BATCH_SIZE, LEASE.as_secs_f64()).await?;
+    let count = due.len();
+    for queued in due {
+        deliver(db, mailer, queued).await?;
+    }
+    Ok(count)
+}
+
+async fn deliver(db: &PgPool, mailer: &dyn Mailer, queued: QueuedEmail) -> ApiResult<()> {
+    let email = OutgoingEmail {
+        to: queued.recipient,
+        subject: queued.subject,
+        text: queued.text_body,
+        html: queued.html_body,
+        unsubscribe_url: queued.unsubscribe_url,
+    };
+    let Err(e) = mailer.send(&email).await else {
+        return services::mark_email_sent(db, queued.id).await;
+    };
+
+    let error = format!("{e:#}");
+    if mailer::is_permanent(&e) || queued.attempts >= MAX_ATTEMPTS {
+        tracing::error!(
+            "Giving up on email {} after {} attempt(s): {error}",
+            queued.id,
+            queued.attempts
+        );
+        services::mark_email_failed(db, queued.id, &error, None).await
+    } else {
+        let retry_at = chrono::Utc::now() + backoff(queued.attempts);
+        tracing::warn!(
+            "Email {} failed (attempt {}), retrying at {retry_at}: {error}",
+            queued.id,
+            queued.attempts
+        );
+        services::mark_email_failed(db, queued.id, &error, Some(retry_at)).await
+    }
+}
+
+/// Delay before the next try after `attempts` failures: one minute, doubling
+/// each time, capped at six hours.
+fn backoff(attempts: i32) -> chrono::Duration {
+    let exponent = attempts.saturating_sub(1).clamp(0, 16) as u32;
+    (FIRST_RETRY * 2_i32.pow(exponent)).min(MAX_RETRY)
+}
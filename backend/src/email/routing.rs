@@ -0,0 +1,456 @@
+// Who hears about new submissions. Contact notifications are routed to teams
+// by source, crop and acreage band; waitlist signups by interest, so pilot
+// signups can go to the pilot lead. Every matching route gets the email, and
+// each route chooses between an email per submission and a daily digest.
+// Submissions no route matches go to `fallback_recipients`, or `ADMIN_EMAIL`
+// when that's empty, which is also the behavior with no routes configured.
+
+use serde::{Deserialize, Serialize};
+use validator::ValidateEmail;
+
+use crate::models::{ContactSubmission, WaitlistEntry};
+use crate::normalize::CROP_TYPES;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RoutingConfig {
+    #[serde(default)]
+    pub contact_routes: Vec<ContactRoute>,
+    #[serde(default)]
+    pub waitlist_routes: Vec<WaitlistRoute>,
+    /// Recipients for submissions no route matches; empty means `ADMIN_EMAIL`.
+    #[serde(default)]
+    pub fallback_recipients: Vec<String>,
+    #[serde(default)]
+    pub fallback_delivery: Delivery,
+    /// Hour of the day (UTC) the digest goes out.
+    #[serde(default = "default_digest_hour")]
+    pub digest_hour_utc: u32,
+}
+
+/// Matches contacts meeting every condition it sets; an empty list or missing
+/// bound matches anything.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContactRoute {
+    pub name: String,
+    #[serde(default)]
+    pub sources: Vec<String>,
+    /// Crop vocabulary ids (see `GET /admin/crop-types`).
+    #[serde(default)]
+    pub crop_types: Vec<String>,
+    /// Acreage band in acres, `min_acres` inclusive and `max_acres` exclusive.
+    /// Contacts whose acreage couldn't be parsed never match a band.
+    pub min_acres: Option<f64>,
+    pub max_acres: Option<f64>,
+    pub recipients: Vec<String>,
+    #[serde(default)]
+    pub delivery: Delivery,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WaitlistRoute {
+    pub name: String,
+    /// Empty matches every interest.
+    #[serde(default)]
+    pub interests: Vec<String>,
+    pub recipients: Vec<String>,
+    #[serde(default)]
+    pub delivery: Delivery,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Delivery {
+    #[default]
+    Immediate,
+    Digest,
+}
+
+/// One address to notify, and the route that picked it.
+#[derive(Debug, Clone)]
+pub struct Recipient {
+    pub address: String,
+    pub route: String,
+    pub delivery: Delivery,
+}
+
+fn default_digest_hour() -> u32 {
+    14
+}
+
+impl Default for RoutingConfig {
+    fn default() -> Self {
+        Self {
+            contact_routes: Vec::new(),
+            waitlist_routes: Vec::new(),
+            fallback_recipients: Vec::new(),
+            fallback_delivery: Delivery::Immediate,
+            digest_hour_utc: default_digest_hour(),
+        }
+    }
+}
+
+impl ContactRoute {
+    fn matches(&self, contact: &ContactSubmission) -> bool {
+        let in_band = match (self.min_acres, self.max_acres) {
+            (None, None) => true,
+            (min, max) => contact.acreage_acres.is_some_and(|acres| {
+                min.is_none_or(|min| acres >= min) && max.is_none_or(|max| acres < max)
+            }),
+        };
+        (self.sources.is_empty() || self.sources.contains(&contact.source))
+            && (self.crop_types.is_empty()
+                || contact
+                    .crop_type_normalized
+                    .as_ref()
+                    .is_some_and(|crop| self.crop_types.contains(crop)))
+            && in_band
+    }
+}
+
+impl RoutingConfig {
+    pub fn contact_recipients(
+        &self,
+        contact: &ContactSubmission,
+        admin_email: Option<&str>,
+    ) -> Vec<Recipient> {
+        let matched = self
+            .contact_routes
+            .iter()
+            .filter(|route| route.matches(contact))
+            .map(|route| (route.name.as_str(), &route.recipients, route.delivery));
+        self.recipients(matched, admin_email)
+    }
+
+    pub fn waitlist_recipients(
+        &self,
+        entry: &WaitlistEntry,
+        admin_email: Option<&str>,
+    ) -> Vec<Recipient> {
+        let matched = self
+            .waitlist_routes
+            .iter()
+            .filter(|route| route.interests.is_empty() || route.interests.contains(&entry.interest))
+            .map(|route| (route.name.as_str(), &route.recipients, route.delivery));
+        self.recipients(matched, admin_email)
+    }
+
+    /// Flatten matched routes into one entry per address. An address reached
+    /// by several routes is emailed right away if any of them says so.
+    fn recipients<'a>(
+        &'a self,
+        matched: impl Iterator<Item = (&'a str, &'a Vec<String>, Delivery)>,
+        admin_email: Option<&str>,
+    ) -> Vec<Recipient> {
+        let mut recipients: Vec<Recipient> = Vec::new();
+        for (route, addresses, delivery) in matched {
+            for address in addresses {
+                add_recipient(&mut recipients, address, route, delivery);
+            }
+        }
+        if recipients.is_empty() {
+            let fallback = match (self.fallback_recipients.as_slice(), admin_email) {
+                ([], Some(admin)) => vec![admin.to_string()],
+                (addresses, _) => addresses.to_vec(),
+            };
+            for address in &fallback {
+                add_recipient(&mut recipients, address, "Unrouted", self.fallback_delivery);
+            }
+        }
+        recipients
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.digest_hour_utc > 23 {
+            return Err("digest_hour_utc must be between 0 and 23".to_string());
+        }
+        let addresses = self
+            .contact_routes
+            .iter()
+            .flat_map(|route| {
+                route
+                    .recipients
+                    .iter()
+                    .map(move |a| (route.name.as_str(), a))
+            })
+            .chain(self.waitlist_routes.iter().flat_map(|route| {
+                route
+                    .recipients
+                    .iter()
+                    .map(move |a| (route.name.as_str(), a))
+            }))
+            .chain(self.fallback_recipients.iter().map(|a| ("fallback", a)));
+        for (route, address) in addresses {
+            if !address.validate_email() {
+                return Err(format!("{route}: '{address}' is not a valid email address"));
+            }
+        }
+        for route in &self.contact_routes {
+            if route.name.trim().is_empty() || route.recipients.is_empty() {
+                return Err(
+                    "Every contact route needs a name and at least one recipient".to_string(),
+                );
+            }
+            if let (Some(min), Some(max)) = (route.min_acres, route.max_acres)
+                && min >= max
+            {
+                return Err(format!("{}: min_acres must be below max_acres", route.name));
+            }
+            if let Some(crop) = route
+                .crop_types
+                .iter()
+                .find(|crop| !CROP_TYPES.iter().any(|known| known.id == crop.as_str()))
+            {
+                return Err(format!("{}: unknown crop type '{crop}'", route.name));
+            }
+        }
+        for route in &self.waitlist_routes {
+            if route.name.trim().is_empty() || route.recipients.is_empty() {
+                return Err(
+                    "Every waitlist route needs a name and at least one recipient".to_string(),
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
+fn add_recipient(recipients: &mut Vec<Recipient>, address: &str, route: &str, delivery: Delivery) {
+    let address = address.trim().to_lowercase();
+    match recipients.iter_mut().find(|r| r.address == address) {
+        Some(existing) if delivery == Delivery::Immediate => {
+            existing.delivery = Delivery::Immediate
+        }
+        Some(_) => {}
+        None => recipients.push(Recipient {
+            address,
+            route: route.to_string(),
+            delivery,
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use serde_json::json;
+    use uuid::Uuid;
+
+    use super::*;
+
+    fn config(value: serde_json::Value) -> RoutingConfig {
+        serde_json::from_value(value).unwrap()
+    }
+
+    fn contact(source: &str, crop: Option<&str>, acres: Option<f64>) -> ContactSubmission {
+        ContactSubmission {
+            id: Uuid::nil(),
+            name: "Ana Ruiz".to_string(),
+            email: "ana@example.com".to_string(),
+            company: None,
+            phone: None,
+            acreage: None,
+            crop_type: None,
+            message: "Hello".to_string(),
+            source: source.to_string(),
+            created_at: Utc::now(),
+            status: "new".to_string(),
+            owner: None,
+            status_changed_at: Utc::now(),
+            person_id: None,
+            visitor_id: None,
+            score: None,
+            score_explanation: None,
+            scored_at: None,
+            acreage_value: acres,
+            acreage_unit: acres.map(|_| "acres".to_string()),
+            acreage_acres: acres,
+            crop_type_normalized: crop.map(str::to_string),
+        }
+    }
+
+    fn waitlist_entry(interest: &str) -> WaitlistEntry {
+        WaitlistEntry {
+            id: Uuid::nil(),
+            email: "ana@example.com".to_string(),
+            name: None,
+            company: None,
+            interest: interest.to_string(),
+            created_at: Utc::now(),
+            person_id: None,
+            visitor_id: None,
+            confirmation_sent_at: None,
+            confirmed_at: Some(Utc::now()),
+            referral_code: "ABCDEFGHJK".to_string(),
+            referred_by: None,
+            pilot_status: "waitlisted".to_string(),
+            cohort_id: None,
+            pilot_status_changed_at: None,
+        }
+    }
+
+    fn addresses(recipients: &[Recipient]) -> Vec<(&str, &str)> {
+        recipients
+            .iter()
+            .map(|r| (r.address.as_str(), r.route.as_str()))
+            .collect()
+    }
+
+    fn routes() -> RoutingConfig {
+        config(json!({
+            "contact_routes": [
+                { "name": "Partners", "sources": ["partner"], "recipients": ["partners@example.com"] },
+                { "name": "Nuts", "crop_types": ["almonds", "walnuts"], "recipients": ["nuts@example.com"] },
+                {
+                    "name": "Large",
+                    "min_acres": 1000.0,
+                    "recipients": ["Sales@Example.com"],
+                    "delivery": "digest"
+                },
+                {
+                    "name": "Mid",
+                    "min_acres": 100.0,
+                    "max_acres": 1000.0,
+                    "recipients": ["sales@example.com"],
+                    "delivery": "digest"
+                }
+            ],
+            "waitlist_routes": [
+                { "name": "Pilot", "interests": ["pilot_2026"], "recipients": ["pilot@example.com"] },
+                { "name": "Everyone", "recipients": ["team@example.com"], "delivery": "digest" }
+            ]
+        }))
+    }
+
+    #[test]
+    fn routes_contacts_by_source() {
+        let recipients = routes().contact_recipients(&contact("partner", None, None), None);
+        assert_eq!(
+            addresses(&recipients),
+            [("partners@example.com", "Partners")]
+        );
+    }
+
+    #[test]
+    fn routes_contacts_by_crop() {
+        let recipients =
+            routes().contact_recipients(&contact("website", Some("walnuts"), None), None);
+        assert_eq!(addresses(&recipients), [("nuts@example.com", "Nuts")]);
+
+        // An unknown or missing crop never matches a crop route
+        let config = routes();
+        assert!(
+            config
+                .contact_recipients(&contact("website", Some("citrus"), None), None)
+                .is_empty()
+        );
+        assert!(
+            config
+                .contact_recipients(&contact("website", None, None), None)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn acreage_bands_include_min_and_exclude_max() {
+        let config = routes();
+        let route_for = |acres| {
+            let recipients = config.contact_recipients(&contact("website", None, acres), None);
+            recipients.first().map(|r| r.route.clone())
+        };
+        assert_eq!(route_for(Some(99.9)), None);
+        assert_eq!(route_for(Some(100.0)).as_deref(), Some("Mid"));
+        assert_eq!(route_for(Some(999.9)).as_deref(), Some("Mid"));
+        assert_eq!(route_for(Some(1000.0)).as_deref(), Some("Large"));
+        assert_eq!(route_for(None), None);
+    }
+
+    #[test]
+    fn every_matching_route_is_notified_in_route_order() {
+        let lead = contact("partner", Some("almonds"), Some(5000.0));
+        let recipients = routes().contact_recipients(&lead, None);
+        assert_eq!(
+            addresses(&recipients),
+            [
+                ("partners@example.com", "Partners"),
+                ("nuts@example.com", "Nuts"),
+                ("sales@example.com", "Large"),
+            ]
+        );
+    }
+
+    #[test]
+    fn an_address_on_several_routes_gets_one_email_and_immediate_wins() {
+        let config = config(json!({
+            "contact_routes": [
+                { "name": "Digest", "recipients": ["ops@example.com"], "delivery": "digest" },
+                { "name": "Now", "recipients": [" OPS@example.com "] }
+            ]
+        }));
+        let recipients = config.contact_recipients(&contact("website", None, None), None);
+        assert_eq!(addresses(&recipients), [("ops@example.com", "Digest")]);
+        assert_eq!(recipients[0].delivery, Delivery::Immediate);
+    }
+
+    #[test]
+    fn routes_waitlist_signups_by_interest() {
+        let config = routes();
+        let pilot = config.waitlist_recipients(&waitlist_entry("pilot_2026"), None);
+        assert_eq!(
+            addresses(&pilot),
+            [
+                ("pilot@example.com", "Pilot"),
+                ("team@example.com", "Everyone")
+            ]
+        );
+        let general = config.waitlist_recipients(&waitlist_entry("general"), None);
+        assert_eq!(addresses(&general), [("team@example.com", "Everyone")]);
+    }
+
+    #[test]
+    fn unrouted_submissions_fall_back_to_admin_email() {
+        let lead = contact("website", None, None);
+        let recipients =
+            RoutingConfig::default().contact_recipients(&lead, Some("admin@example.com"));
+        assert_eq!(addresses(&recipients), [("admin@example.com", "Unrouted")]);
+        assert_eq!(recipients[0].delivery, Delivery::Immediate);
+
+        assert!(
+            RoutingConfig::default()
+                .contact_recipients(&lead, None)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn fallback_recipients_replace_admin_email() {
+        let config = config(json!({
+            "fallback_recipients": ["triage@example.com"],
+            "fallback_delivery": "digest"
+        }));
+        let recipients =
+            config.waitlist_recipients(&waitlist_entry("general"), Some("admin@example.com"));
+        assert_eq!(addresses(&recipients), [("triage@example.com", "Unrouted")]);
+        assert_eq!(recipients[0].delivery, Delivery::Digest);
+    }
+
+    #[test]
+    fn validate_rejects_bad_routes() {
+        assert!(routes().validate().is_ok());
+        let invalid = [
+            json!({ "digest_hour_utc": 24 }),
+            json!({ "fallback_recipients": ["not-an-email"] }),
+            json!({ "contact_routes": [{ "name": "", "recipients": ["a@example.com"] }] }),
+            json!({ "contact_routes": [{ "name": "Empty", "recipients": [] }] }),
+            json!({ "contact_routes": [{
+                "name": "Band", "min_acres": 500.0, "max_acres": 500.0, "recipients": ["a@example.com"]
+            }] }),
+            json!({ "contact_routes": [{
+                "name": "Crop", "crop_types": ["almond"], "recipients": ["a@example.com"]
+            }] }),
+            json!({ "waitlist_routes": [{ "name": " ", "recipients": ["a@example.com"] }] }),
+        ];
+        for value in invalid {
+            assert!(config(value.clone()).validate().is_err(), "{value}");
+        }
+    }
+}
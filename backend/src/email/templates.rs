@@ -0,0 +1,58 @@
+// Email bodies. Every message has a plain-text and an HTML template under
+// `templates/`, compiled into the binary; both extend a shared layout, and the
+// HTML ones are auto-escaped.
+
+use std::sync::LazyLock;
+
+use minijinja::{Environment, Value};
+
+macro_rules! templates {
+    ($($name:literal),* $(,)?) => {
+        &[$(($name, include_str!(concat!("templates/", $name)))),*]
+    };
+}
+
+const TEMPLATES: &[(&str, &str)] = templates![
+    "layout.html",
+    "layout.txt",
+    "macros.html",
+    "contact_notification.html",
+    "contact_notification.txt",
+    "waitlist_notification.html",
+    "waitlist_notification.txt",
+    "waitlist_confirmation.html",
+    "waitlist_confirmation.txt",
+    "pilot_status.html",
+    "pilot_status.txt",
+    "erasure_link.html",
+    "erasure_link.txt",
+    "access_link.html",
+    "access_link.txt",
+    "auto_reply.html",
+    "auto_reply.txt",
+    "daily_digest.html",
+    "daily_digest.txt",
+];
+
+static ENV: LazyLock<Environment<'static>> = LazyLock::new(|| {
+    let mut env = Environment::new();
+    env.set_trim_blocks(true);
+    env.set_lstrip_blocks(true);
+    for (name, source) in TEMPLATES {
+        env.add_template(name, source)
+            .unwrap_or_else(|e| panic!("invalid email template {name}: {e}"));
+    }
+    env
+});
+
+pub struct Rendered {
+    pub text: String,
+    pub html: String,
+}
+
+/// Render `<name>.txt` and `<name>.html` with the same context.
+pub fn render(name: &str, ctx: Value) -> anyhow::Result<Rendered> {
+    let text = ENV.get_template(&format!("{name}.txt"))?.render(&ctx)?;
+    let html = ENV.get_template(&format!("{name}.html"))?.render(&ctx)?;
+    Ok(Rendered { text, html })
+}
@@ -0,0 +1,317 @@
+// Bulk admin exports of contacts and waitlist entries for the weekly CRM and
+// mailing-tool imports. CSV is written one row at a time so a response can be
+// streamed straight from the database cursor; XLSX uses the writer's
+// constant-memory mode and is assembled once the last row is in.
+//
+// Also packages subject access bundles (everything about one person) as a
+// single JSON document or a ZIP of one JSON file per section.
+
+use std::io::{Cursor, Write};
+
+use chrono::{DateTime, Utc};
+use rust_xlsxwriter::{Format, Workbook, XlsxError};
+use serde::Deserialize;
+use zip::write::SimpleFileOptions;
+use zip::{CompressionMethod, ZipWriter};
+
+use crate::models::{ContactSubmission, SubjectAccessBundle, WaitlistEntry};
+
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportFormat {
+    #[default]
+    Csv,
+    Xlsx,
+}
+
+impl ExportFormat {
+    pub fn content_type(self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "text/csv; charset=utf-8",
+            ExportFormat::Xlsx => {
+                "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet"
+            }
+        }
+    }
+
+    pub fn extension(self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "csv",
+            ExportFormat::Xlsx => "xlsx",
+        }
+    }
+}
+
+pub enum Cell {
+    Text(String),
+    Number(f64),
+    Time(DateTime<Utc>),
+    Empty,
+}
+
+impl From<&str> for Cell {
+    fn from(s: &str) -> Self {
+        Cell::Text(s.to_string())
+    }
+}
+
+impl From<&Option<String>> for Cell {
+    fn from(s: &Option<String>) -> Self {
+        s.as_deref().map_or(Cell::Empty, Cell::from)
+    }
+}
+
+impl From<Option<DateTime<Utc>>> for Cell {
+    fn from(t: Option<DateTime<Utc>>) -> Self {
+        t.map_or(Cell::Empty, Cell::Time)
+    }
+}
+
+impl From<Option<f64>> for Cell {
+    fn from(n: Option<f64>) -> Self {
+        n.map_or(Cell::Empty, Cell::Number)
+    }
+}
+
+impl From<Option<i32>> for Cell {
+    fn from(n: Option<i32>) -> Self {
+        n.map_or(Cell::Empty, |n| Cell::Number(f64::from(n)))
+    }
+}
+
+/// A row type that can be exported: fixed column headers and one cell per column.
+pub trait ExportRow {
+    const HEADERS: &'static [&'static str];
+
+    fn cells(&self) -> Vec<Cell>;
+}
+
+impl ExportRow for ContactSubmission {
+    const HEADERS: &'static [&'static str] = &[
+        "id",
+        "created_at",
+        "name",
+        "email",
+        "company",
+        "phone",
+        "acreage",
+        "acreage_acres",
+        "crop_type",
+        "crop_type_normalized",
+        "source",
+        "status",
+        "owner",
+        "score",
+        "message",
+    ];
+
+    fn cells(&self) -> Vec<Cell> {
+        vec![
+            Cell::Text(self.id.to_string()),
+            Cell::Time(self.created_at),
+            self.name.as_str().into(),
+            self.email.as_str().into(),
+            (&self.company).into(),
+            (&self.phone).into(),
+            (&self.acreage).into(),
+            self.acreage_acres.into(),
+            (&self.crop_type).into(),
+            (&self.crop_type_normalized).into(),
+            self.source.as_str().into(),
+            self.status.as_str().into(),
+            (&self.owner).into(),
+            self.score.into(),
+            self.message.as_str().into(),
+        ]
+    }
+}
+
+impl ExportRow for WaitlistEntry {
+    const HEADERS: &'static [&'static str] = &[
+        "id",
+        "created_at",
+        "confirmed_at",
+        "email",
+        "name",
+        "company",
+        "interest",
+        "referral_code",
+        "referred_by",
+    ];
+
+    fn cells(&self) -> Vec<Cell> {
+        vec![
+            Cell::Text(self.id.to_string()),
+            Cell::Time(self.created_at),
+            self.confirmed_at.into(),
+            self.email.as_str().into(),
+            (&self.name).into(),
+            (&self.company).into(),
+            self.interest.as_str().into(),
+            self.referral_code.as_str().into(),
+            self.referred_by
+                .map_or(Cell::Empty, |id| Cell::Text(id.to_string())),
+        ]
+    }
+}
+
+pub fn csv_header<R: ExportRow>() -> Vec<u8> {
+    csv_line(R::HEADERS.iter().map(|h| h.to_string()))
+}
+
+pub fn csv_row<R: ExportRow>(row: &R) -> Vec<u8> {
+    csv_line(row.cells().into_iter().map(|cell| match cell {
+        Cell::Text(s) => defuse_formula(s),
+        Cell::Number(n) => n.to_string(),
+        Cell::Time(t) => t.to_rfc3339(),
+        Cell::Empty => String::new(),
+    }))
+}
+
+fn csv_line(fields: impl IntoIterator<Item = String>) -> Vec<u8> {
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    // Writing to a Vec cannot fail
+    let _ = writer.write_record(fields);
+    writer.into_inner().unwrap_or_default()
+}
+
+/// Submissions come from public forms, and spreadsheet apps evaluate CSV cells
+/// starting with these characters as formulas. A leading quote keeps them text.
+fn defuse_formula(s: String) -> String {
+    if s.starts_with(['=', '+', '-', '@', '\t', '\r']) {
+        format!("'{s}")
+    } else {
+        s
+    }
+}
+
+pub struct XlsxExport {
+    workbook: Workbook,
+    next_row: u32,
+}
+
+impl XlsxExport {
+    pub fn new<R: ExportRow>() -> Result<Self, XlsxError> {
+        let mut workbook = Workbook::new();
+        let sheet = workbook.add_worksheet_with_constant_memory();
+        let bold = Format::new().set_bold();
+        for (col, header) in R::HEADERS.iter().enumerate() {
+            sheet.write_string_with_format(0, col as u16, *header, &bold)?;
+        }
+        Ok(Self {
+            workbook,
+            next_row: 1,
+        })
+    }
+
+    pub fn push<R: ExportRow>(&mut self, row: &R) -> Result<(), XlsxError> {
+        let sheet = self.workbook.worksheet_from_index(0)?;
+        for (col, cell) in row.cells().into_iter().enumerate() {
+            let col = col as u16;
+            match cell {
+                Cell::Text(s) => sheet.write_string(self.next_row, col, s)?,
+                Cell::Number(n) => sheet.write_number(self.next_row, col, n)?,
+                Cell::Time(t) => sheet.write_string(
+                    self.next_row,
+                    col,
+                    t.format("%Y-%m-%d %H:%M:%S").to_string(),
+                )?,
+                Cell::Empty => sheet,
+            };
+        }
+        self.next_row += 1;
+        Ok(())
+    }
+
+    pub fn finish(mut self) -> Result<Vec<u8>, XlsxError> {
+        self.workbook.save_to_buffer()
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BundleFormat {
+    #[default]
+    Json,
+    Zip,
+}
+
+impl BundleFormat {
+    pub fn content_type(self) -> &'static str {
+        match self {
+            BundleFormat::Json => "application/json",
+            BundleFormat::Zip => "application/zip",
+        }
+    }
+
+    pub fn extension(self) -> &'static str {
+        match self {
+            BundleFormat::Json => "json",
+            BundleFormat::Zip => "zip",
+        }
+    }
+}
+
+const BUNDLE_README: &str = "\
+This archive holds every record Terroir AI stores about the email address in
+person.json, as of the time in generated_at.json.
+
+  person.json               your contact details as we last saw them
+  contact_submissions.json  messages sent through our contact form
+  contact_notes.json        notes our team added to those submissions
+  waitlist_entries.json     waitlist and pilot program signups
+  visitor_ids.json          anonymous browser IDs linked to you when you confirmed your email
+  page_views.json           pages viewed from those browsers
+  events.json               site interactions (clicks, downloads) from those browsers
+
+To have this data deleted, use the \"Delete my data\" link in the site footer.
+";
+
+pub fn subject_access_json(bundle: &SubjectAccessBundle) -> anyhow::Result<Vec<u8>> {
+    Ok(serde_json::to_vec_pretty(bundle)?)
+}
+
+pub fn subject_access_zip(bundle: &SubjectAccessBundle) -> anyhow::Result<Vec<u8>> {
+    let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+    let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+
+    zip.start_file("README.txt", options)?;
+    zip.write_all(BUNDLE_README.as_bytes())?;
+
+    let sections = [
+        (
+            "generated_at",
+            serde_json::to_vec_pretty(&bundle.generated_at)?,
+        ),
+        (
+            "person",
+            serde_json::to_vec_pretty(
+                &serde_json::json!({ "email": bundle.email, "person": bundle.person }),
+            )?,
+        ),
+        (
+            "contact_submissions",
+            serde_json::to_vec_pretty(&bundle.contact_submissions)?,
+        ),
+        (
+            "contact_notes",
+            serde_json::to_vec_pretty(&bundle.contact_notes)?,
+        ),
+        (
+            "waitlist_entries",
+            serde_json::to_vec_pretty(&bundle.waitlist_entries)?,
+        ),
+        (
+            "visitor_ids",
+            serde_json::to_vec_pretty(&bundle.visitor_ids)?,
+        ),
+        ("page_views", serde_json::to_vec_pretty(&bundle.page_views)?),
+        ("events", serde_json::to_vec_pretty(&bundle.events)?),
+    ];
+    for (name, json) in sections {
+        zip.start_file(format!("{name}.json"), options)?;
+        zip.write_all(&json)?;
+    }
+
+    Ok(zip.finish()?.into_inner())
+}
@@ -0,0 +1,206 @@
+// RSS 2.0 and Atom 1.0 feeds for the public blog.
+
+use chrono::{DateTime, Utc};
+
+use crate::models::BlogPostSummary;
+
+const FEED_TITLE: &str = "Terroir AI Blog";
+const FEED_DESCRIPTION: &str =
+    "Field intelligence, labor and regulatory insights for specialty crop growers.";
+
+pub fn rss(base_url: &str, posts: &[BlogPostSummary]) -> String {
+    let base = base_url.trim_end_matches('/');
+    let blog = xml_escape(&format!("{base}/blog"));
+    let last_build = posts
+        .iter()
+        .map(|p| p.updated_at)
+        .max()
+        .unwrap_or_else(Utc::now);
+
+    let mut xml = format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<rss version="2.0" xmlns:dc="http://purl.org/dc/elements/1.1/">
+<channel>
+  <title>{title}</title>
+  <link>{blog}</link>
+  <description>{description}</description>
+  <language>en-us</language>
+  <lastBuildDate>{last_build}</lastBuildDate>
+"#,
+        title = FEED_TITLE,
+        description = FEED_DESCRIPTION,
+        last_build = last_build.to_rfc2822(),
+    );
+
+    for post in posts {
+        let link = format!("{base}/blog/{}", post.slug);
+        xml.push_str(&format!(
+            r#"  <item>
+    <title>{title}</title>
+    <link>{link}</link>
+    <guid isPermaLink="true">{link}</guid>
+    <description>{excerpt}</description>
+    <dc:creator>{author}</dc:creator>
+    <category>{category}</category>
+    <pubDate>{date}</pubDate>
+  </item>
+"#,
+            title = xml_escape(&post.title),
+            link = xml_escape(&link),
+            excerpt = xml_escape(&post.excerpt),
+            author = xml_escape(&post.author_name),
+            category = xml_escape(&post.category),
+            date = published(post).to_rfc2822(),
+        ));
+    }
+
+    xml.push_str("</channel>\n</rss>\n");
+    xml
+}
+
+pub fn atom(base_url: &str, posts: &[BlogPostSummary]) -> String {
+    let base = base_url.trim_end_matches('/');
+    let blog = xml_escape(&format!("{base}/blog"));
+    let updated = posts
+        .iter()
+        .map(|p| p.updated_at)
+        .max()
+        .unwrap_or_else(Utc::now);
+
+    let mut xml = format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<feed xmlns="http://www.w3.org/2005/Atom">
+  <title>{title}</title>
+  <subtitle>{description}</subtitle>
+  <id>{blog}</id>
+  <link rel="alternate" type="text/html" href="{blog}"/>
+  <updated>{updated}</updated>
+"#,
+        title = FEED_TITLE,
+        description = FEED_DESCRIPTION,
+        updated = updated.to_rfc3339(),
+    );
+
+    for post in posts {
+        let link = xml_escape(&format!("{base}/blog/{}", post.slug));
+        xml.push_str(&format!(
+            r#"  <entry>
+    <title>{title}</title>
+    <link rel="alternate" type="text/html" href="{link}"/>
+    <id>{link}</id>
+    <published>{published}</published>
+    <updated>{updated}</updated>
+    <author><name>{author}</name></author>
+    <category term="{category}"/>
+    <summary>{excerpt}</summary>
+  </entry>
+"#,
+            title = xml_escape(&post.title),
+            published = published(post).to_rfc3339(),
+            updated = post.updated_at.to_rfc3339(),
+            author = xml_escape(&post.author_name),
+            category = xml_escape(&post.category),
+            excerpt = xml_escape(&post.excerpt),
+        ));
+    }
+
+    xml.push_str("</feed>\n");
+    xml
+}
+
+/// Escape text for use in XML element content and double-quoted attributes.
+pub fn xml_escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&apos;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+fn published(post: &BlogPostSummary) -> DateTime<Utc> {
+    post.published_at.unwrap_or(post.updated_at)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+    use uuid::Uuid;
+
+    use super::*;
+
+    fn post(slug: &str, title: &str) -> BlogPostSummary {
+        BlogPostSummary {
+            id: Uuid::nil(),
+            slug: slug.to_string(),
+            title: title.to_string(),
+            subtitle: None,
+            category: "labor".to_string(),
+            hero_image_url: None,
+            excerpt: "Crews <b>&</b> costs".to_string(),
+            author_name: "O'Neil".to_string(),
+            read_time_minutes: 3,
+            published_at: Some(Utc.with_ymd_and_hms(2026, 3, 9, 7, 5, 0).unwrap()),
+            updated_at: Utc.with_ymd_and_hms(2026, 3, 10, 12, 0, 0).unwrap(),
+            tags: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn escapes_markup_characters() {
+        let escaped = xml_escape(r#"a & b < c > "d" 'e'"#);
+        assert_eq!(escaped, "a &amp; b &lt; c &gt; &quot;d&quot; &apos;e&apos;");
+    }
+
+    #[test]
+    fn rss_escapes_slugs_and_text() {
+        let xml = rss(
+            "https://example.com/?a=1&b=2/",
+            &[post("h2a-&-<you>", "Q&A: \"H-2A\"")],
+        );
+        assert!(
+            xml.contains("<link>https://example.com/?a=1&amp;b=2/blog</link>"),
+            "{xml}"
+        );
+        let item = "<link>https://example.com/?a=1&amp;b=2/blog/h2a-&amp;-&lt;you&gt;</link>";
+        assert!(xml.contains(item), "{xml}");
+        assert!(xml.contains("<title>Q&amp;A: &quot;H-2A&quot;</title>"));
+        assert!(xml.contains("<description>Crews &lt;b&gt;&amp;&lt;/b&gt; costs</description>"));
+        assert!(xml.contains("<dc:creator>O&apos;Neil</dc:creator>"));
+        assert!(
+            xml.contains("<pubDate>Mon, 9 Mar 2026 07:05:00 +0000</pubDate>"),
+            "{xml}"
+        );
+        assert!(xml.contains("<lastBuildDate>Tue, 10 Mar 2026 12:00:00 +0000</lastBuildDate>"));
+    }
+
+    #[test]
+    fn atom_escapes_link_attributes() {
+        let xml = atom("https://example.com", &[post("a\"b", "Title")]);
+        assert!(
+            xml.contains(r#"href="https://example.com/blog/a&quot;b""#),
+            "{xml}"
+        );
+        assert!(xml.contains("<id>https://example.com/blog/a&quot;b</id>"));
+        assert!(
+            xml.contains("<published>2026-03-09T07:05:00+00:00</published>"),
+            "{xml}"
+        );
+        assert!(xml.contains("<updated>2026-03-10T12:00:00+00:00</updated>"));
+    }
+
+    #[test]
+    fn unpublished_posts_fall_back_to_their_update_time() {
+        let draft = BlogPostSummary {
+            published_at: None,
+            ..post("a", "A")
+        };
+        assert_eq!(published(&draft), draft.updated_at);
+    }
+}
@@ -14,10 +14,17 @@ use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
 mod api;
 mod config;
+mod content;
 mod email;
 mod errors;
+mod export;
+mod feeds;
 mod models;
+mod normalize;
+mod scoring;
 mod services;
+mod sitemap;
+mod tokens;
 
 use config::AppConfig;
 
@@ -26,6 +33,8 @@ use config::AppConfig;
 pub struct AppState {
     pub db: sqlx::PgPool,
     pub config: Arc<AppConfig>,
+    pub mailer: Arc<dyn email::Mailer>,
+    pub outbox: email::Outbox,
 }
 
 #[tokio::main]
@@ -35,9 +44,10 @@ async fn main() -> Result<()> {
 
     // Structured JSON logging
     tracing_subscriber::registry()
-        .with(tracing_subscriber::EnvFilter::try_from_default_env().unwrap_or_else(|_| {
-            "terroir_api=info,tower_http=info,sqlx=warn".into()
-        }))
+        .with(
+            tracing_subscriber::EnvFilter::try_from_default_env()
+                .unwrap_or_else(|_| "terroir_api=info,tower_http=info,sqlx=warn".into()),
+        )
         .with(tracing_subscriber::fmt::layer().json())
         .init();
 
@@ -54,9 +64,41 @@ async fn main() -> Result<()> {
     sqlx::migrate!("./migrations").run(&pool).await?;
     tracing::info!("Migrations applied");
 
+    // Parse acreage/crop on rows that predate the normalizer, once per
+    // database, and rescore so scores reflect the parsed values. In the
+    // background so a cold start never waits on it.
+    let backfill_pool = pool.clone();
+    tokio::spawn(async move {
+        match services::backfill_contact_normalization(&backfill_pool).await {
+            Ok(Some(normalized)) if normalized > 0 => {
+                match services::rescore_contacts(&backfill_pool).await {
+                    Ok(rescored) => {
+                        tracing::info!("Normalized {normalized} contacts; rescored {rescored}")
+                    }
+                    Err(e) => tracing::error!("Rescoring after contact normalization failed: {e}"),
+                }
+            }
+            Ok(_) => {}
+            Err(e) => tracing::error!("Contact normalization backfill failed: {e}"),
+        }
+    });
+
+    let mailer = email::mailer::from_config(&config)?;
+    tracing::info!("Mail transport: {:?}", config.mail_transport);
+    let outbox = email::Outbox::new(pool.clone());
+    let shared_config = Arc::new(config.clone());
+    if config.background_workers {
+        outbox.spawn_worker(mailer.clone());
+        email::digest::spawn_worker(outbox.clone(), pool.clone(), shared_config.clone());
+    } else {
+        tracing::info!("Background workers off; outbox and digests run when the scheduler calls");
+    }
+
     let state = AppState {
         db: pool,
-        config: Arc::new(config.clone()),
+        config: shared_config,
+        mailer,
+        outbox,
     };
 
     // CORS
@@ -1,9 +1,13 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
 use uuid::Uuid;
 use validator::Validate;
 
+use crate::email::auto_reply::AutoReplyConfig;
+use crate::email::routing::RoutingConfig;
+use crate::scoring::ScoringConfig;
+
 // ── Blog Posts ───────────────────────────────
 
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
@@ -15,6 +19,7 @@ pub struct BlogPost {
     pub category: String,
     pub hero_image_url: Option<String>,
     pub content_html: String,
+    pub content_markdown: Option<String>,
     pub excerpt: String,
     pub author_name: String,
     pub read_time_minutes: i32,
@@ -36,6 +41,229 @@ pub struct BlogPostSummary {
     pub author_name: String,
     pub read_time_minutes: i32,
     pub published_at: Option<DateTime<Utc>>,
+    pub updated_at: DateTime<Utc>,
+    pub tags: Vec<String>,
+}
+
+/// A post plus its tags, as returned by the single-post endpoints.
+#[derive(Debug, Serialize)]
+pub struct BlogPostDetail {
+    #[serde(flatten)]
+    pub post: BlogPost,
+    pub tags: Vec<String>,
+}
+
+/// Public single-post response: the post, its tags and a few related reads.
+#[derive(Debug, Serialize)]
+pub struct BlogPostPage {
+    #[serde(flatten)]
+    pub detail: BlogPostDetail,
+    pub related: Vec<BlogPostSummary>,
+}
+
+/// A search hit: the post summary plus a `<mark>`-highlighted body fragment.
+#[derive(Debug, Serialize)]
+pub struct BlogSearchResult {
+    #[serde(flatten)]
+    pub post: BlogPostSummary,
+    pub snippet: String,
+    pub rank: f32,
+}
+
+#[derive(Debug, Serialize, FromRow)]
+pub struct BlogCategory {
+    pub category: String,
+    pub post_count: i64,
+}
+
+#[derive(Debug, Serialize, FromRow)]
+pub struct BlogTag {
+    pub slug: String,
+    pub name: String,
+    pub post_count: i64,
+}
+
+/// Admin create/update payload. Send the body as `content_markdown` (preferred)
+/// or `content_html`; `excerpt` and `read_time_minutes` are computed from the
+/// rendered text unless given. An update replaces the whole post, so send back
+/// every field to keep, including `tags` and `published_at`.
+#[derive(Debug, Deserialize, Validate)]
+#[validate(schema(function = "validate_blog_content"))]
+pub struct BlogPostRequest {
+    #[validate(length(min = 1, max = 300), custom(function = "validate_slug"))]
+    pub slug: String,
+    #[validate(length(min = 1, max = 500))]
+    pub title: String,
+    #[validate(length(max = 500))]
+    pub subtitle: Option<String>,
+    #[validate(length(min = 1, max = 100))]
+    pub category: Option<String>,
+    #[validate(url)]
+    pub hero_image_url: Option<String>,
+    #[validate(length(max = 200_000))]
+    pub content_markdown: Option<String>,
+    #[validate(length(max = 200_000))]
+    pub content_html: Option<String>,
+    #[validate(length(max = 1000))]
+    pub excerpt: Option<String>,
+    #[validate(length(min = 1, max = 200))]
+    pub author_name: Option<String>,
+    #[validate(range(min = 1, max = 120))]
+    pub read_time_minutes: Option<i32>,
+    /// The post's full tag set; omitted means no tags.
+    #[validate(length(max = 20), custom(function = "validate_tags"))]
+    pub tags: Option<Vec<String>>,
+    #[serde(default)]
+    pub published: bool,
+    /// A future timestamp on a published post schedules it; it stays hidden
+    /// from the public blog and sitemap until then. Omitted on a published post
+    /// means now; omitted on a draft clears it.
+    pub published_at: Option<DateTime<Utc>>,
+}
+
+/// Paths under `/blog/` that are routes of their own; a post with one of these
+/// slugs could never be reached.
+const RESERVED_SLUGS: &[&str] = &["categories", "tags", "search"];
+
+/// Slugs end up in URLs and the sitemap, so keep them to `a-z`, `0-9` and `-`.
+fn validate_slug(slug: &str) -> Result<(), validator::ValidationError> {
+    if RESERVED_SLUGS.contains(&slug) {
+        return Err(validator::ValidationError::new("slug")
+            .with_message(format!("'{slug}' is reserved for a blog route").into()));
+    }
+    let valid = slug
+        .chars()
+        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+        && !slug.starts_with('-')
+        && !slug.ends_with('-');
+    if valid {
+        Ok(())
+    } else {
+        Err(validator::ValidationError::new("slug")
+            .with_message("must contain only lowercase letters, digits and hyphens".into()))
+    }
+}
+
+fn validate_tags(tags: &[String]) -> Result<(), validator::ValidationError> {
+    if tags.iter().all(|t| (1..=100).contains(&t.trim().len())) {
+        Ok(())
+    } else {
+        Err(validator::ValidationError::new("tags")
+            .with_message("each tag must be 1-100 characters".into()))
+    }
+}
+
+fn validate_blog_content(req: &BlogPostRequest) -> Result<(), validator::ValidationError> {
+    let has_body = [&req.content_markdown, &req.content_html]
+        .into_iter()
+        .any(|body| body.as_deref().is_some_and(|b| !b.trim().is_empty()));
+    if has_body {
+        Ok(())
+    } else {
+        Err(validator::ValidationError::new("content")
+            .with_message("content_markdown or content_html is required".into()))
+    }
+}
+
+// ── Blog Metrics ─────────────────────────────
+
+/// Engagement for one post over a date range. Conversions count distinct
+/// readers who fired a contact or waitlist/pilot signup event after first
+/// viewing the post.
+#[derive(Debug, Serialize, FromRow)]
+pub struct BlogPostMetrics {
+    pub post_id: Uuid,
+    pub slug: String,
+    pub title: String,
+    pub published_at: Option<DateTime<Utc>>,
+    pub views: i64,
+    pub unique_visitors: i64,
+    /// Mean of each reader's deepest `blog_read` milestone, in percent.
+    pub avg_scroll_depth: Option<f64>,
+    pub contact_conversions: i64,
+    pub waitlist_conversions: i64,
+}
+
+// ── Sitemap ──────────────────────────────────
+
+#[derive(Debug, FromRow)]
+pub struct SitemapPost {
+    pub slug: String,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Visible post count plus the latest time any of them changed or went live;
+/// drives the sitemap's ETag and Last-Modified headers.
+#[derive(Debug, FromRow)]
+pub struct SitemapStats {
+    pub post_count: i64,
+    pub last_modified: Option<DateTime<Utc>>,
+}
+
+// ── Blog Revisions ───────────────────────────
+
+#[derive(Debug, Clone, Serialize, FromRow)]
+pub struct BlogPostRevision {
+    pub id: Uuid,
+    /// `None` once the post has been deleted; its history is kept.
+    pub post_id: Option<Uuid>,
+    pub revision_number: i32,
+    pub slug: String,
+    pub title: String,
+    pub subtitle: Option<String>,
+    pub category: String,
+    pub hero_image_url: Option<String>,
+    pub content_html: String,
+    pub content_markdown: Option<String>,
+    pub excerpt: String,
+    pub author_name: String,
+    pub read_time_minutes: i32,
+    pub published: bool,
+    pub published_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    /// Tag names, sorted; `None` for revisions recorded before tags were.
+    pub tags: Option<Vec<String>>,
+    /// The id the post had, once it's deleted; restoring brings it back.
+    pub deleted_post_id: Option<Uuid>,
+}
+
+#[derive(Debug, Serialize, FromRow)]
+pub struct BlogPostRevisionSummary {
+    pub id: Uuid,
+    pub revision_number: i32,
+    pub slug: String,
+    pub title: String,
+    pub published: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+/// A deleted post, as its latest revision left it.
+#[derive(Debug, Serialize, FromRow)]
+pub struct DeletedBlogPost {
+    /// The post's old id; its revisions are listed and restored under it.
+    pub id: Uuid,
+    pub slug: String,
+    pub title: String,
+    pub revision_count: i64,
+    pub latest_revision: i32,
+    pub last_revised_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BlogRevisionDiff {
+    pub post_id: Uuid,
+    pub from_revision: i32,
+    pub to_revision: i32,
+    pub changed_fields: Vec<FieldChange>,
+    /// Unified diff of the post body (Markdown source when both revisions have it).
+    pub content_diff: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct FieldChange {
+    pub field: &'static str,
+    pub from: Option<String>,
+    pub to: Option<String>,
 }
 
 // ── Contact / Lead Forms ─────────────────────
@@ -52,6 +280,20 @@ pub struct ContactSubmission {
     pub message: String,
     pub source: String,
     pub created_at: DateTime<Utc>,
+    pub status: String,
+    pub owner: Option<String>,
+    pub status_changed_at: DateTime<Utc>,
+    pub person_id: Option<Uuid>,
+    pub visitor_id: Option<String>,
+    pub score: Option<i32>,
+    pub score_explanation: Option<String>,
+    pub scored_at: Option<DateTime<Utc>>,
+    /// Parsed from `acreage`; `acreage_acres` converts hectares for comparison.
+    pub acreage_value: Option<f64>,
+    pub acreage_unit: Option<String>,
+    pub acreage_acres: Option<f64>,
+    /// `crop_type` mapped onto the crop vocabulary (`GET /admin/crop-types`).
+    pub crop_type_normalized: Option<String>,
 }
 
 #[derive(Debug, Deserialize, Validate)]
@@ -72,12 +314,140 @@ pub struct ContactRequest {
     pub message: String,
     #[validate(length(max = 100))]
     pub source: Option<String>,
+    /// Analytics visitor ID from the browser, used to link browsing history.
+    #[validate(length(min = 1, max = 64))]
+    #[serde(default)]
+    pub visitor_id: Option<String>,
     #[serde(default)]
     pub website: Option<String>,
     #[serde(default)]
     pub _form_loaded_at: Option<i64>,
 }
 
+// ── Lead Pipeline ────────────────────────────
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LeadStatus {
+    New,
+    Contacted,
+    Qualified,
+    Pilot,
+    ClosedLost,
+}
+
+impl LeadStatus {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            LeadStatus::New => "new",
+            LeadStatus::Contacted => "contacted",
+            LeadStatus::Qualified => "qualified",
+            LeadStatus::Pilot => "pilot",
+            LeadStatus::ClosedLost => "closed_lost",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "new" => Some(LeadStatus::New),
+            "contacted" => Some(LeadStatus::Contacted),
+            "qualified" => Some(LeadStatus::Qualified),
+            "pilot" => Some(LeadStatus::Pilot),
+            "closed_lost" => Some(LeadStatus::ClosedLost),
+            _ => None,
+        }
+    }
+
+    /// Open leads move forward (stages may be skipped) or drop to closed-lost;
+    /// a closed-lost lead can only be reopened as new.
+    pub fn can_transition_to(self, next: LeadStatus) -> bool {
+        match (self, next) {
+            (LeadStatus::ClosedLost, LeadStatus::New) => true,
+            (LeadStatus::ClosedLost, _) => false,
+            (_, LeadStatus::ClosedLost) => true,
+            (from, to) => to > from,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct LeadStatusRequest {
+    pub status: LeadStatus,
+    #[validate(length(min = 1, max = 200))]
+    pub changed_by: Option<String>,
+    /// Optional note recorded alongside the status change.
+    #[validate(length(min = 1, max = 5000))]
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct LeadOwnerRequest {
+    /// `null` unassigns the lead.
+    #[validate(length(min = 1, max = 200))]
+    pub owner: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct ContactNoteRequest {
+    #[validate(length(min = 1, max = 200))]
+    pub author: Option<String>,
+    #[validate(length(min = 1, max = 5000))]
+    pub body: String,
+}
+
+#[derive(Debug, Serialize, FromRow)]
+pub struct ContactNote {
+    pub id: Uuid,
+    pub contact_id: Uuid,
+    pub author: Option<String>,
+    pub body: String,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, FromRow)]
+pub struct ContactStatusChange {
+    pub id: Uuid,
+    pub contact_id: Uuid,
+    pub from_status: String,
+    pub to_status: String,
+    pub changed_by: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ContactDetail {
+    #[serde(flatten)]
+    pub contact: ContactSubmission,
+    pub notes: Vec<ContactNote>,
+    pub status_history: Vec<ContactStatusChange>,
+    pub emails: Vec<OutboxEmail>,
+}
+
+#[derive(Debug, Serialize, FromRow)]
+pub struct PipelineStage {
+    pub status: String,
+    pub count: i64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ScoringConfigResponse {
+    pub config: ScoringConfig,
+    pub updated_by: Option<String>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct ScoringConfigRequest {
+    pub config: ScoringConfig,
+    #[validate(length(min = 1, max = 200))]
+    pub updated_by: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RescoreResult {
+    pub rescored: usize,
+}
+
 // ── Waitlist ─────────────────────────────────
 
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
@@ -88,6 +458,20 @@ pub struct WaitlistEntry {
     pub company: Option<String>,
     pub interest: String,
     pub created_at: DateTime<Utc>,
+    pub person_id: Option<Uuid>,
+    pub visitor_id: Option<String>,
+    pub confirmation_sent_at: Option<DateTime<Utc>>,
+    /// Set when the subscriber clicks the emailed link; unconfirmed entries are
+    /// left out of stats and exports.
+    pub confirmed_at: Option<DateTime<Utc>>,
+    /// Shared as `?ref=` on the site; unique per entry.
+    pub referral_code: String,
+    /// The entry whose referral code this signup arrived with.
+    pub referred_by: Option<Uuid>,
+    /// Pilot acceptance workflow; see `PilotStatus`.
+    pub pilot_status: String,
+    pub cohort_id: Option<Uuid>,
+    pub pilot_status_changed_at: Option<DateTime<Utc>>,
 }
 
 #[derive(Debug, Deserialize, Validate)]
@@ -100,12 +484,427 @@ pub struct WaitlistRequest {
     pub company: Option<String>,
     #[validate(length(max = 100))]
     pub interest: Option<String>,
+    #[validate(length(min = 1, max = 64))]
+    #[serde(default)]
+    pub visitor_id: Option<String>,
+    /// Referral code from the `?ref=` the visitor landed with.
+    #[validate(length(min = 1, max = 16))]
+    #[serde(default, rename = "ref")]
+    pub ref_code: Option<String>,
     #[serde(default)]
     pub website: Option<String>,
     #[serde(default)]
     pub _form_loaded_at: Option<i64>,
 }
 
+#[derive(Debug, Deserialize, Validate)]
+pub struct WaitlistConfirmRequest {
+    #[validate(length(min = 1, max = 500))]
+    pub token: String,
+    /// Analytics visitor ID of the browser that opened the link; only linked to
+    /// the person once the token checks out.
+    #[validate(length(min = 1, max = 64))]
+    #[serde(default)]
+    pub visitor_id: Option<String>,
+}
+
+/// What a subscriber can see about their own spot, looked up by referral code.
+#[derive(Debug, Serialize)]
+pub struct WaitlistStatus {
+    pub referral_code: String,
+    pub interest: String,
+    pub confirmed: bool,
+    /// 1-based place among confirmed entries with the same interest.
+    pub position: Option<i64>,
+    /// Confirmed signups that used this entry's code.
+    pub referral_count: i64,
+}
+
+#[derive(Debug, Serialize, FromRow)]
+pub struct ReferralLeader {
+    pub id: Uuid,
+    pub email: String,
+    pub name: Option<String>,
+    pub company: Option<String>,
+    pub interest: String,
+    pub referral_code: String,
+    pub referral_count: i64,
+    /// Referred signups that have not confirmed yet.
+    pub pending_referrals: i64,
+}
+
+// ── Pilot Cohorts ────────────────────────────
+
+#[derive(Debug, Clone, Serialize, FromRow)]
+pub struct PilotCohort {
+    pub id: Uuid,
+    pub name: String,
+    /// Waitlist interest the cohort draws from.
+    pub interest: String,
+    pub region: Option<String>,
+    /// Crop vocabulary id (see `GET /admin/crop-types`).
+    pub crop_focus: Option<String>,
+    pub capacity: i32,
+    pub starts_on: Option<NaiveDate>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct PilotCohortRequest {
+    #[validate(length(min = 1, max = 200))]
+    pub name: String,
+    /// Defaults to `pilot_2026`.
+    #[validate(length(min = 1, max = 100))]
+    pub interest: Option<String>,
+    #[validate(length(min = 1, max = 200))]
+    pub region: Option<String>,
+    #[validate(length(min = 1, max = 50))]
+    pub crop_focus: Option<String>,
+    #[validate(range(min = 1, max = 10000))]
+    pub capacity: i32,
+    pub starts_on: Option<NaiveDate>,
+}
+
+/// A cohort with its seat counts. Invited and accepted entries hold a seat.
+#[derive(Debug, Serialize)]
+pub struct CohortFill {
+    #[serde(flatten)]
+    pub cohort: PilotCohort,
+    pub invited: i64,
+    pub accepted: i64,
+    pub declined: i64,
+    pub waitlisted: i64,
+    pub seats_remaining: i64,
+    /// Accepted entries as a share of capacity, 0.0–1.0.
+    pub fill_rate: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PilotStatus {
+    Pending,
+    Invited,
+    Accepted,
+    Declined,
+    Waitlisted,
+}
+
+impl PilotStatus {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PilotStatus::Pending => "pending",
+            PilotStatus::Invited => "invited",
+            PilotStatus::Accepted => "accepted",
+            PilotStatus::Declined => "declined",
+            PilotStatus::Waitlisted => "waitlisted",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "pending" => Some(PilotStatus::Pending),
+            "invited" => Some(PilotStatus::Invited),
+            "accepted" => Some(PilotStatus::Accepted),
+            "declined" => Some(PilotStatus::Declined),
+            "waitlisted" => Some(PilotStatus::Waitlisted),
+            _ => None,
+        }
+    }
+
+    pub fn holds_seat(self) -> bool {
+        matches!(self, PilotStatus::Invited | PilotStatus::Accepted)
+    }
+
+    /// Nothing returns to pending, an accepted grower can only drop out, and a
+    /// declined one has to be invited or waitlisted again before accepting.
+    pub fn can_transition_to(self, next: PilotStatus) -> bool {
+        match (self, next) {
+            (from, to) if from == to => false,
+            (_, PilotStatus::Pending) => false,
+            (PilotStatus::Accepted, to) => to == PilotStatus::Declined,
+            (PilotStatus::Declined, PilotStatus::Accepted) => false,
+            _ => true,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct PilotStatusRequest {
+    pub status: PilotStatus,
+    /// Required to invite or accept unless the entry is already in a cohort.
+    pub cohort_id: Option<Uuid>,
+    #[validate(length(min = 1, max = 200))]
+    pub changed_by: Option<String>,
+    /// Email the grower about the change; defaults to true.
+    pub notify: Option<bool>,
+}
+
+#[derive(Debug, Serialize, FromRow)]
+pub struct PilotStatusChange {
+    pub id: Uuid,
+    pub entry_id: Uuid,
+    pub cohort_id: Option<Uuid>,
+    pub from_status: String,
+    pub to_status: String,
+    pub changed_by: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PilotEntryDetail {
+    #[serde(flatten)]
+    pub entry: WaitlistEntry,
+    pub cohort: Option<PilotCohort>,
+    pub status_history: Vec<PilotStatusChange>,
+    pub emails: Vec<OutboxEmail>,
+}
+
+// ── People ───────────────────────────────────
+
+#[derive(Debug, Serialize, FromRow)]
+pub struct Person {
+    pub id: Uuid,
+    pub email: String,
+    pub name: Option<String>,
+    pub company: Option<String>,
+    pub first_seen_at: DateTime<Utc>,
+    pub last_seen_at: DateTime<Utc>,
+    /// Opted out of email via the signed unsubscribe link.
+    pub unsubscribed_at: Option<DateTime<Utc>>,
+    pub erasure_requested_at: Option<DateTime<Utc>>,
+    pub access_requested_at: Option<DateTime<Utc>>,
+}
+
+/// One entry in a person's history: a form submission, lead update, page view
+/// or analytics event. `detail` carries the kind-specific fields.
+#[derive(Debug, Serialize, FromRow)]
+pub struct TimelineItem {
+    pub kind: String,
+    pub at: DateTime<Utc>,
+    pub title: String,
+    pub detail: serde_json::Value,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PersonTimeline {
+    #[serde(flatten)]
+    pub person: Person,
+    pub visitor_ids: Vec<String>,
+    pub timeline: Vec<TimelineItem>,
+}
+
+// ── Privacy ──────────────────────────────────
+
+/// Body for the unsubscribe and erasure links, which carry a signed token.
+#[derive(Debug, Deserialize, Validate)]
+pub struct PrivacyTokenRequest {
+    #[validate(length(min = 1, max = 500))]
+    pub token: String,
+}
+
+/// A delete-my-data or access request: the address to act on, plus the
+/// anti-spam fields every public form carries.
+#[derive(Debug, Deserialize, Validate)]
+pub struct PrivacyRequest {
+    #[validate(email, length(max = 300))]
+    pub email: String,
+    #[serde(default)]
+    pub website: Option<String>,
+    #[serde(default)]
+    pub _form_loaded_at: Option<i64>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct AdminErasureRequest {
+    #[validate(email, length(max = 300))]
+    pub email: String,
+    #[validate(length(min = 1, max = 200))]
+    pub performed_by: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum ErasureVia {
+    SelfService,
+    Admin,
+}
+
+impl ErasureVia {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ErasureVia::SelfService => "self_service",
+            ErasureVia::Admin => "admin",
+        }
+    }
+}
+
+/// Everything stored about one email, as handed over for a subject access
+/// request.
+#[derive(Debug, Serialize)]
+pub struct SubjectAccessBundle {
+    pub generated_at: DateTime<Utc>,
+    pub email: String,
+    pub person: Option<Person>,
+    pub contact_submissions: Vec<ContactSubmission>,
+    pub contact_notes: Vec<ContactNote>,
+    pub waitlist_entries: Vec<WaitlistEntry>,
+    pub visitor_ids: Vec<String>,
+    pub page_views: Vec<PageViewRecord>,
+    pub events: Vec<EventRecord>,
+}
+
+#[derive(Debug, Serialize, FromRow)]
+pub struct PageViewRecord {
+    pub id: i64,
+    pub session_id: String,
+    pub visitor_id: String,
+    pub path: String,
+    pub referrer: Option<String>,
+    pub utm_source: Option<String>,
+    pub utm_medium: Option<String>,
+    pub utm_campaign: Option<String>,
+    pub utm_term: Option<String>,
+    pub utm_content: Option<String>,
+    pub user_agent: Option<String>,
+    pub screen_width: Option<i32>,
+    pub country: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, FromRow)]
+pub struct EventRecord {
+    pub id: i64,
+    pub session_id: String,
+    pub visitor_id: String,
+    pub event_name: String,
+    pub event_category: String,
+    pub event_label: Option<String>,
+    pub event_value: Option<f64>,
+    pub properties: Option<serde_json::Value>,
+    pub path: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Audit record of one erasure. The email is stored only as a SHA-256 hash of
+/// its normalized form.
+#[derive(Debug, Serialize, FromRow)]
+pub struct DataErasure {
+    pub id: Uuid,
+    pub email_sha256: String,
+    pub requested_via: String,
+    pub performed_by: Option<String>,
+    pub contacts_deleted: i64,
+    pub waitlist_deleted: i64,
+    pub visitor_ids: i64,
+    pub page_views_anonymized: i64,
+    pub events_anonymized: i64,
+    pub created_at: DateTime<Utc>,
+}
+
+// ── Email Outbox ─────────────────────────────
+
+/// The submission an email is about. Its outbox rows are deleted with it.
+#[derive(Debug, Clone, Copy)]
+pub enum EmailAbout {
+    Contact(Uuid),
+    WaitlistEntry(Uuid),
+}
+
+/// An outbox row as admins see it, without the bodies.
+#[derive(Debug, Serialize, FromRow)]
+pub struct OutboxEmail {
+    pub id: Uuid,
+    pub kind: String,
+    pub recipient: String,
+    pub subject: String,
+    pub contact_id: Option<Uuid>,
+    pub waitlist_entry_id: Option<Uuid>,
+    pub status: String,
+    pub attempts: i32,
+    pub next_attempt_at: DateTime<Utc>,
+    pub last_error: Option<String>,
+    pub sent_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// A message claimed by the delivery worker.
+#[derive(Debug, FromRow)]
+pub struct QueuedEmail {
+    pub id: Uuid,
+    pub recipient: String,
+    pub subject: String,
+    pub text_body: String,
+    pub html_body: Option<String>,
+    pub unsubscribe_url: Option<String>,
+    pub attempts: i32,
+}
+
+/// What one scheduled outbox run did.
+#[derive(Debug, Serialize)]
+pub struct OutboxDelivery {
+    /// Messages claimed and tried, whether or not they went out.
+    pub attempted: usize,
+    /// Sent and dead messages past retention that were deleted.
+    pub purged: u64,
+}
+
+#[derive(Debug, Serialize)]
+pub struct OutboxStats {
+    pub pending: i64,
+    /// Pending messages that have already failed at least once.
+    pub retrying: i64,
+    pub sent: i64,
+    pub dead: i64,
+    pub oldest_pending_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AutoReplyConfigResponse {
+    pub config: AutoReplyConfig,
+    pub updated_by: Option<String>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct AutoReplyConfigRequest {
+    pub config: AutoReplyConfig,
+    #[validate(length(min = 1, max = 200))]
+    pub updated_by: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct RoutingConfigResponse {
+    pub config: RoutingConfig,
+    pub updated_by: Option<String>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct RoutingConfigRequest {
+    pub config: RoutingConfig,
+    #[validate(length(min = 1, max = 200))]
+    pub updated_by: Option<String>,
+}
+
+/// A submission waiting for `recipient`'s daily digest.
+#[derive(Debug, Clone, FromRow)]
+pub struct DigestItem {
+    pub id: Uuid,
+    pub recipient: String,
+    pub route: String,
+    pub contact_id: Option<Uuid>,
+    pub waitlist_entry_id: Option<Uuid>,
+}
+
+/// Digest items claimed for sending, with the submissions they refer to.
+#[derive(Debug)]
+pub struct DigestBatch {
+    pub items: Vec<DigestItem>,
+    pub contacts: Vec<ContactSubmission>,
+    pub waitlist_entries: Vec<WaitlistEntry>,
+}
+
 // ── Admin ────────────────────────────────────
 
 #[derive(Debug, Serialize)]
@@ -116,6 +915,67 @@ pub struct AdminStats {
     pub waitlist_today: i64,
 }
 
+/// Filters for the admin contact list. `search` matches name, email or company.
+#[derive(Debug, Default)]
+pub struct ContactFilter {
+    pub search: Option<String>,
+    pub status: Option<String>,
+    pub owner: Option<String>,
+    pub source: Option<String>,
+    pub crop_type: Option<String>,
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Default)]
+pub struct WaitlistFilter {
+    pub search: Option<String>,
+    pub interest: Option<String>,
+    pub confirmed: Option<bool>,
+    pub pilot_status: Option<String>,
+    pub cohort_id: Option<Uuid>,
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+}
+
+/// Position after the last row of a page, newest first. `score` is only set
+/// when paging by score (unscored rows sort as `i32::MIN`).
+#[derive(Debug, Clone, Copy)]
+pub struct SubmissionCursor {
+    pub score: Option<i32>,
+    pub created_at: DateTime<Utc>,
+    pub id: Uuid,
+}
+
+impl SubmissionCursor {
+    /// Opaque, URL-safe form: `[score_]micros_id`.
+    pub fn encode(&self) -> String {
+        let position = format!("{}_{}", self.created_at.timestamp_micros(), self.id.simple());
+        match self.score {
+            Some(score) => format!("{score}_{position}"),
+            None => position,
+        }
+    }
+
+    pub fn decode(s: &str, with_score: bool) -> Option<Self> {
+        let mut parts = s.split('_');
+        let score = if with_score { Some(parts.next()?.parse().ok()?) } else { None };
+        let created_at = DateTime::from_timestamp_micros(parts.next()?.parse().ok()?)?;
+        let id = parts.next()?.parse().ok()?;
+        if parts.next().is_some() {
+            return None;
+        }
+        Some(Self { score, created_at, id })
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    /// Pass back as `cursor` for the next page; absent on the last page.
+    pub next_cursor: Option<String>,
+}
+
 // ── Health ────────────────────────────────────
 
 #[derive(Debug, Serialize)]
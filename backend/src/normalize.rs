@@ -0,0 +1,405 @@
+// Normalization of the free-text farm fields on contact submissions. The raw
+// input is always kept; these parsed values sit alongside it for scoring,
+// filtering and reporting.
+
+use serde::Serialize;
+
+/// Anything larger is a typo or a unit mix-up, not a farm.
+const MAX_AREA: f64 = 10_000_000.0;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AreaUnit {
+    Acres,
+    Hectares,
+}
+
+impl AreaUnit {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            AreaUnit::Acres => "acres",
+            AreaUnit::Hectares => "hectares",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Acreage {
+    pub value: f64,
+    pub unit: AreaUnit,
+}
+
+/// Parse a farm size such as "500ac", "~1k acres", "1,200", "80 ha" or
+/// "500-700 acres" (ranges take the midpoint). A bare number is acres.
+pub fn parse_acreage(raw: &str) -> Option<Acreage> {
+    let text = raw.to_lowercase().replace(',', "");
+    let (mut low, low_scaled, rest) = leading_number(&text)?;
+    let (high, rest) = match range_end(rest) {
+        Some((high, high_scaled, rest)) => {
+            // "1-2k" means 1,000–2,000, not 1–2,000
+            if high_scaled && !low_scaled {
+                low *= 1000.0;
+            }
+            (high, rest)
+        }
+        None => (low, rest),
+    };
+
+    let value = (low + high) / 2.0;
+    if !value.is_finite() || value <= 0.0 || value > MAX_AREA {
+        return None;
+    }
+
+    let unit = rest
+        .split(|c: char| !c.is_alphabetic())
+        .find_map(|word| match word {
+            "ha" | "hectare" | "hectares" | "hectareas" => Some(AreaUnit::Hectares),
+            "ac" | "acre" | "acres" => Some(AreaUnit::Acres),
+            _ => None,
+        })
+        .unwrap_or(AreaUnit::Acres);
+
+    Some(Acreage { value, unit })
+}
+
+/// First number in `text`, with a trailing "k"/"thousand" applied. Returns the
+/// value, whether a multiplier was applied, and the unparsed remainder.
+fn leading_number(text: &str) -> Option<(f64, bool, &str)> {
+    let start = text.find(|c: char| c.is_ascii_digit())?;
+    let text = &text[start..];
+    let end = text
+        .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .unwrap_or(text.len());
+    let value: f64 = text[..end].trim_end_matches('.').parse().ok()?;
+
+    let rest = &text[end..];
+    let trimmed = rest.trim_start();
+    if let Some(after) = trimmed.strip_prefix("thousand") {
+        return Some((value * 1000.0, true, after));
+    }
+    if let Some(after) = trimmed.strip_prefix('k')
+        && !after.starts_with(|c: char| c.is_alphabetic())
+    {
+        return Some((value * 1000.0, true, after));
+    }
+    Some((value, false, rest))
+}
+
+/// Upper bound of a range like "-700" or " to 700" directly after a number.
+fn range_end(rest: &str) -> Option<(f64, bool, &str)> {
+    let trimmed = rest.trim_start();
+    let after = trimmed
+        .strip_prefix('-')
+        .or_else(|| trimmed.strip_prefix('–'))
+        .or_else(|| trimmed.strip_prefix("to "))?
+        .trim_start();
+    if !after.starts_with(|c: char| c.is_ascii_digit()) {
+        return None;
+    }
+    leading_number(after)
+}
+
+#[derive(Debug, Serialize)]
+pub struct CropType {
+    pub id: &'static str,
+    pub label: &'static str,
+    pub synonyms: &'static [&'static str],
+}
+
+/// Catch-all for a crop that matches nothing in the vocabulary.
+pub const OTHER_CROP: &str = "other";
+
+/// Controlled crop vocabulary. Entries are checked in order, so specific crops
+/// come before the catch-alls ("grapes/wine" is wine grapes, not just grapes).
+pub const CROP_TYPES: &[CropType] = &[
+    CropType {
+        id: "wine_grapes",
+        label: "Wine grapes",
+        synonyms: &[
+            "wine",
+            "wine grape",
+            "wine grapes",
+            "winegrape",
+            "winegrapes",
+            "vineyard",
+            "vineyards",
+            "winery",
+            "vinifera",
+            "cabernet",
+            "chardonnay",
+            "pinot",
+            "merlot",
+            "zinfandel",
+        ],
+    },
+    CropType {
+        id: "table_grapes",
+        label: "Table grapes",
+        synonyms: &["table grape", "table grapes", "raisin", "raisins"],
+    },
+    CropType {
+        id: "grapes",
+        label: "Grapes (unspecified)",
+        synonyms: &["grape", "grapes", "vine", "vines", "viticulture"],
+    },
+    CropType {
+        id: "almonds",
+        label: "Almonds",
+        synonyms: &["almond", "almonds"],
+    },
+    CropType {
+        id: "walnuts",
+        label: "Walnuts",
+        synonyms: &["walnut", "walnuts"],
+    },
+    CropType {
+        id: "pistachios",
+        label: "Pistachios",
+        synonyms: &["pistachio", "pistachios"],
+    },
+    CropType {
+        id: "citrus",
+        label: "Citrus",
+        synonyms: &[
+            "citrus",
+            "orange",
+            "oranges",
+            "lemon",
+            "lemons",
+            "lime",
+            "limes",
+            "mandarin",
+            "mandarins",
+            "grapefruit",
+            "tangerine",
+            "tangerines",
+            "clementine",
+            "clementines",
+        ],
+    },
+    CropType {
+        id: "avocados",
+        label: "Avocados",
+        synonyms: &["avocado", "avocados", "avo", "avos"],
+    },
+    CropType {
+        id: "berries",
+        label: "Berries",
+        synonyms: &[
+            "berry",
+            "berries",
+            "strawberry",
+            "strawberries",
+            "blueberry",
+            "blueberries",
+            "raspberry",
+            "raspberries",
+            "blackberry",
+            "blackberries",
+            "cranberry",
+            "cranberries",
+        ],
+    },
+    CropType {
+        id: "cherries",
+        label: "Cherries",
+        synonyms: &["cherry", "cherries"],
+    },
+    CropType {
+        id: "apples",
+        label: "Apples",
+        synonyms: &["apple", "apples"],
+    },
+    CropType {
+        id: "pears",
+        label: "Pears",
+        synonyms: &["pear", "pears"],
+    },
+    CropType {
+        id: "stone_fruit",
+        label: "Stone fruit",
+        synonyms: &[
+            "stone fruit",
+            "stonefruit",
+            "peach",
+            "peaches",
+            "nectarine",
+            "nectarines",
+            "plum",
+            "plums",
+            "apricot",
+            "apricots",
+            "prune",
+            "prunes",
+        ],
+    },
+    CropType {
+        id: "olives",
+        label: "Olives",
+        synonyms: &["olive", "olives"],
+    },
+    CropType {
+        id: "hops",
+        label: "Hops",
+        synonyms: &["hop", "hops"],
+    },
+    CropType {
+        id: "tree_nuts",
+        label: "Other tree nuts",
+        synonyms: &[
+            "pecan",
+            "pecans",
+            "hazelnut",
+            "hazelnuts",
+            "filbert",
+            "filberts",
+            "macadamia",
+            "macadamias",
+            "nut",
+            "nuts",
+            "tree nut",
+            "tree nuts",
+        ],
+    },
+    CropType {
+        id: "vegetables",
+        label: "Vegetables",
+        synonyms: &[
+            "vegetable",
+            "vegetables",
+            "veg",
+            "veggies",
+            "lettuce",
+            "tomato",
+            "tomatoes",
+            "pepper",
+            "peppers",
+            "broccoli",
+            "onion",
+            "onions",
+            "carrot",
+            "carrots",
+            "leafy greens",
+            "row crop",
+            "row crops",
+        ],
+    },
+];
+
+/// Map a free-text crop onto the vocabulary. Non-empty input that matches no
+/// entry becomes `OTHER_CROP`.
+pub fn normalize_crop(raw: &str) -> Option<&'static str> {
+    let words: Vec<&str> = raw
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .collect();
+    if words.is_empty() {
+        return None;
+    }
+    let text = format!(" {} ", words.join(" ").to_lowercase());
+
+    let crop = CROP_TYPES
+        .iter()
+        .find(|crop| {
+            crop.synonyms
+                .iter()
+                .any(|s| text.contains(&format!(" {s} ")))
+        })
+        .map_or(OTHER_CROP, |crop| crop.id);
+    Some(crop)
+}
+
+pub fn crop_label(id: &str) -> &str {
+    CROP_TYPES
+        .iter()
+        .find(|crop| crop.id == id)
+        .map_or(id, |crop| crop.label)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_acreage() {
+        let acres = |value| {
+            Some(Acreage {
+                value,
+                unit: AreaUnit::Acres,
+            })
+        };
+        let hectares = |value| {
+            Some(Acreage {
+                value,
+                unit: AreaUnit::Hectares,
+            })
+        };
+        let cases = [
+            ("500", acres(500.0)),
+            ("500ac", acres(500.0)),
+            ("500 acres", acres(500.0)),
+            ("1,200", acres(1200.0)),
+            ("~1k acres", acres(1000.0)),
+            ("2.5k", acres(2500.0)),
+            ("3 thousand acres", acres(3000.0)),
+            ("80 ha", hectares(80.0)),
+            ("120 Hectares", hectares(120.0)),
+            ("500-700 acres", acres(600.0)),
+            ("500–700 acres", acres(600.0)),
+            ("500 to 700", acres(600.0)),
+            ("1-2k", acres(1500.0)),
+            ("1k-2k", acres(1500.0)),
+            ("about 40 acres of almonds", acres(40.0)),
+            ("", None),
+            ("lots", None),
+            ("0", None),
+            ("20000000", None),
+        ];
+        for (raw, expected) in cases {
+            assert_eq!(parse_acreage(raw), expected, "{raw:?}");
+        }
+    }
+
+    #[test]
+    fn a_k_that_starts_a_word_is_not_a_multiplier() {
+        assert_eq!(
+            parse_acreage("40 km away"),
+            Some(Acreage {
+                value: 40.0,
+                unit: AreaUnit::Acres
+            })
+        );
+    }
+
+    #[test]
+    fn maps_crops_onto_the_vocabulary() {
+        let cases = [
+            ("Almonds", Some("almonds")),
+            ("grapes/wine", Some("wine_grapes")),
+            ("Wine Grapes", Some("wine_grapes")),
+            ("table grapes", Some("table_grapes")),
+            ("grapes", Some("grapes")),
+            ("navel oranges", Some("citrus")),
+            ("stone fruit", Some("stone_fruit")),
+            ("pecans and walnuts", Some("walnuts")),
+            ("tobacco", Some(OTHER_CROP)),
+            ("", None),
+            (" / ", None),
+        ];
+        for (raw, expected) in cases {
+            assert_eq!(normalize_crop(raw), expected, "{raw:?}");
+        }
+    }
+
+    #[test]
+    fn synonyms_match_whole_words() {
+        // "vine" is a grape synonym, but not inside "vinegar"
+        assert_eq!(normalize_crop("vinegar"), Some(OTHER_CROP));
+        assert_eq!(normalize_crop("pearl onions"), Some("vegetables"));
+    }
+
+    #[test]
+    fn labels_fall_back_to_the_id() {
+        assert_eq!(crop_label("tree_nuts"), "Other tree nuts");
+        assert_eq!(crop_label("mystery"), "mystery");
+    }
+}
@@ -0,0 +1,396 @@
+// Inbound lead scoring. A contact's score is the sum of points for farm size,
+// crop, how complete the submission is, where it came from, and how engaged the
+// visitor was on the site. Weights live in `ScoringConfig`, which admins can
+// replace at runtime; the defaults below favor large tree-nut and grape growers.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::ContactSubmission;
+use crate::normalize;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScoringConfig {
+    /// Checked in order; the first band whose `min_acres` the lead meets wins.
+    pub acreage_bands: Vec<AcreageBand>,
+    /// Points per crop, keyed by crop vocabulary id (see `normalize::CROP_TYPES`).
+    pub crop_weights: HashMap<String, i32>,
+    /// Points for a crop with no weight of its own, including "other".
+    pub other_crop_points: i32,
+    pub company_points: i32,
+    pub phone_points: i32,
+    pub source_weights: HashMap<String, i32>,
+    pub page_view_points: i32,
+    /// Points per analytics event by `event_name`.
+    pub event_weights: HashMap<String, i32>,
+    /// Ceiling on the combined page-view and event points.
+    pub engagement_cap: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AcreageBand {
+    pub min_acres: f64,
+    pub points: i32,
+}
+
+impl Default for ScoringConfig {
+    fn default() -> Self {
+        let weights = |pairs: &[(&str, i32)]| {
+            pairs
+                .iter()
+                .map(|(k, v)| (k.to_string(), *v))
+                .collect::<HashMap<_, _>>()
+        };
+        Self {
+            acreage_bands: vec![
+                AcreageBand {
+                    min_acres: 2000.0,
+                    points: 40,
+                },
+                AcreageBand {
+                    min_acres: 500.0,
+                    points: 30,
+                },
+                AcreageBand {
+                    min_acres: 100.0,
+                    points: 15,
+                },
+                AcreageBand {
+                    min_acres: 0.0,
+                    points: 5,
+                },
+            ],
+            crop_weights: weights(&[
+                ("almonds", 20),
+                ("walnuts", 20),
+                ("pistachios", 20),
+                ("wine_grapes", 18),
+                ("table_grapes", 15),
+                ("grapes", 15),
+                ("tree_nuts", 15),
+                ("citrus", 12),
+                ("avocados", 12),
+                ("berries", 12),
+                ("cherries", 12),
+                ("apples", 10),
+                ("stone_fruit", 10),
+                ("vegetables", 8),
+            ]),
+            other_crop_points: 5,
+            company_points: 5,
+            phone_points: 5,
+            source_weights: weights(&[("referral", 10), ("partner", 10), ("website_contact", 5)]),
+            page_view_points: 1,
+            event_weights: weights(&[
+                ("trial_start", 10),
+                ("waitlist_join", 8),
+                ("blog_read", 2),
+                ("cta_click", 2),
+                ("download_click", 3),
+                ("share_click", 1),
+            ]),
+            engagement_cap: 25,
+        }
+    }
+}
+
+impl ScoringConfig {
+    /// Bands are matched first-to-last, so they must run from the largest
+    /// `min_acres` down; otherwise a small band would shadow the ones below it.
+    pub fn validate(&self) -> Result<(), String> {
+        self.check_crop_ids()?;
+        if let Some(band) = self
+            .acreage_bands
+            .iter()
+            .find(|b| !b.min_acres.is_finite() || b.min_acres < 0.0)
+        {
+            return Err(format!(
+                "acreage_bands: min_acres {} must be zero or more",
+                band.min_acres
+            ));
+        }
+        if let Some(pair) = self
+            .acreage_bands
+            .windows(2)
+            .find(|pair| pair[0].min_acres <= pair[1].min_acres)
+        {
+            return Err(format!(
+                "acreage_bands must be in descending order of min_acres ({} comes before {})",
+                pair[0].min_acres, pair[1].min_acres
+            ));
+        }
+        Ok(())
+    }
+
+    /// `crop_weights` keys must be crop vocabulary ids; a free-text keyword
+    /// would never match a normalized crop.
+    pub fn check_crop_ids(&self) -> Result<(), String> {
+        match self.crop_weights.keys().find(|key| {
+            !normalize::CROP_TYPES
+                .iter()
+                .any(|crop| crop.id == key.as_str())
+        }) {
+            Some(key) => Err(format!("crop_weights: unknown crop type '{key}'")),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Site activity from every visitor ID linked to the lead.
+#[derive(Debug, Default)]
+pub struct Engagement {
+    pub page_views: i64,
+    pub events: HashMap<String, i64>,
+}
+
+pub struct LeadScore {
+    pub score: i32,
+    /// Short human-readable breakdown, e.g. "2,000 ac (+40) · almonds (+20)".
+    pub explanation: String,
+}
+
+pub fn score_contact(
+    config: &ScoringConfig,
+    contact: &ContactSubmission,
+    engagement: &Engagement,
+) -> LeadScore {
+    let mut parts: Vec<(String, i32)> = Vec::new();
+
+    if let Some(acres) = contact.acreage_acres
+        && let Some(band) = config.acreage_bands.iter().find(|b| acres >= b.min_acres)
+    {
+        parts.push((format!("{} ac", group_thousands(acres)), band.points));
+    }
+
+    if let Some(crop) = contact.crop_type_normalized.as_deref() {
+        let points = config
+            .crop_weights
+            .get(crop)
+            .copied()
+            .unwrap_or(config.other_crop_points);
+        parts.push((normalize::crop_label(crop).to_lowercase(), points));
+    }
+
+    if contact
+        .company
+        .as_deref()
+        .is_some_and(|c| !c.trim().is_empty())
+    {
+        parts.push(("company".to_string(), config.company_points));
+    }
+    if contact
+        .phone
+        .as_deref()
+        .is_some_and(|p| !p.trim().is_empty())
+    {
+        parts.push(("phone".to_string(), config.phone_points));
+    }
+    if let Some(points) = config.source_weights.get(&contact.source) {
+        parts.push((format!("source {}", contact.source), *points));
+    }
+
+    let event_points: i64 = engagement
+        .events
+        .iter()
+        .filter_map(|(name, count)| {
+            config
+                .event_weights
+                .get(name)
+                .map(|w| i64::from(*w) * count)
+        })
+        .sum();
+    let engagement_points = (engagement.page_views * i64::from(config.page_view_points)
+        + event_points)
+        .min(i64::from(config.engagement_cap)) as i32;
+    if engagement_points > 0 {
+        let events: i64 = engagement.events.values().sum();
+        parts.push((
+            format!("{} page views, {events} events", engagement.page_views),
+            engagement_points,
+        ));
+    }
+
+    LeadScore {
+        score: parts.iter().map(|(_, points)| points).sum(),
+        explanation: parts
+            .iter()
+            .filter(|(_, points)| *points != 0)
+            .map(|(label, points)| format!("{label} ({points:+})"))
+            .collect::<Vec<_>>()
+            .join(" · "),
+    }
+}
+
+fn group_thousands(acres: f64) -> String {
+    let digits = (acres.round() as i64).to_string();
+    let mut out = String::with_capacity(digits.len() + digits.len() / 3);
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            out.push(',');
+        }
+        out.push(c);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use uuid::Uuid;
+
+    use super::*;
+
+    fn contact() -> ContactSubmission {
+        ContactSubmission {
+            id: Uuid::nil(),
+            name: "Ana Ruiz".to_string(),
+            email: "ana@example.com".to_string(),
+            company: None,
+            phone: None,
+            acreage: None,
+            crop_type: None,
+            message: "Hello".to_string(),
+            source: "website".to_string(),
+            created_at: Utc::now(),
+            status: "new".to_string(),
+            owner: None,
+            status_changed_at: Utc::now(),
+            person_id: None,
+            visitor_id: None,
+            score: None,
+            score_explanation: None,
+            scored_at: None,
+            acreage_value: None,
+            acreage_unit: None,
+            acreage_acres: None,
+            crop_type_normalized: None,
+        }
+    }
+
+    fn score(contact: &ContactSubmission) -> LeadScore {
+        score_contact(&ScoringConfig::default(), contact, &Engagement::default())
+    }
+
+    #[test]
+    fn acreage_takes_the_first_band_it_reaches() {
+        let cases = [
+            (2500.0, 40),
+            (2000.0, 40),
+            (1999.0, 30),
+            (500.0, 30),
+            (100.0, 15),
+            (40.0, 5),
+            (0.0, 5),
+        ];
+        for (acres, points) in cases {
+            let lead = ContactSubmission {
+                acreage_acres: Some(acres),
+                ..contact()
+            };
+            assert_eq!(score(&lead).score, points, "{acres} ac");
+        }
+    }
+
+    #[test]
+    fn crops_use_their_weight_or_the_fallback() {
+        let cases = [
+            ("almonds", 20),
+            ("wine_grapes", 18),
+            ("vegetables", 8),
+            ("other", 5),
+        ];
+        for (crop, points) in cases {
+            let lead = ContactSubmission {
+                crop_type_normalized: Some(crop.to_string()),
+                ..contact()
+            };
+            assert_eq!(score(&lead).score, points, "{crop}");
+        }
+    }
+
+    #[test]
+    fn completeness_and_source_add_points() {
+        let lead = ContactSubmission {
+            company: Some("Ruiz Ranch".to_string()),
+            phone: Some("555-0100".to_string()),
+            source: "referral".to_string(),
+            ..contact()
+        };
+        assert_eq!(score(&lead).score, 5 + 5 + 10);
+
+        let blank = ContactSubmission {
+            company: Some("  ".to_string()),
+            phone: Some(String::new()),
+            ..contact()
+        };
+        assert_eq!(score(&blank).score, 0);
+    }
+
+    #[test]
+    fn engagement_is_capped() {
+        let engagement = Engagement {
+            page_views: 12,
+            events: HashMap::from([("trial_start".to_string(), 2), ("unknown".to_string(), 5)]),
+        };
+        let lead = score_contact(&ScoringConfig::default(), &contact(), &engagement);
+        assert_eq!(lead.score, 25);
+        assert_eq!(lead.explanation, "12 page views, 7 events (+25)");
+    }
+
+    #[test]
+    fn explanation_lists_each_part_and_skips_zeros() {
+        let config = ScoringConfig {
+            company_points: 0,
+            ..ScoringConfig::default()
+        };
+        let lead = ContactSubmission {
+            acreage_acres: Some(2400.4),
+            crop_type_normalized: Some("almonds".to_string()),
+            company: Some("Ruiz Ranch".to_string()),
+            source: "partner".to_string(),
+            ..contact()
+        };
+        let scored = score_contact(&config, &lead, &Engagement::default());
+        assert_eq!(scored.score, 40 + 20 + 10);
+        assert_eq!(
+            scored.explanation,
+            "2,400 ac (+40) · almonds (+20) · source partner (+10)"
+        );
+        assert_eq!(score(&contact()).explanation, "");
+    }
+
+    #[test]
+    fn thousands_are_grouped() {
+        let cases = [
+            (0.0, "0"),
+            (999.0, "999"),
+            (1000.0, "1,000"),
+            (123456.6, "123,457"),
+            (1e6, "1,000,000"),
+        ];
+        for (acres, text) in cases {
+            assert_eq!(group_thousands(acres), text);
+        }
+    }
+
+    #[test]
+    fn validate_rejects_misordered_bands_and_unknown_crops() {
+        assert!(ScoringConfig::default().validate().is_ok());
+
+        let mut config = ScoringConfig::default();
+        config.acreage_bands.swap(0, 1);
+        assert!(config.validate().unwrap_err().contains("descending"));
+
+        let mut config = ScoringConfig::default();
+        config.acreage_bands[3].min_acres = -1.0;
+        assert!(config.validate().unwrap_err().contains("zero or more"));
+
+        let mut config = ScoringConfig::default();
+        config.crop_weights.insert("almond".to_string(), 5);
+        assert_eq!(
+            config.validate().unwrap_err(),
+            "crop_weights: unknown crop type 'almond'"
+        );
+    }
+}
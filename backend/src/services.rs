@@ -1,83 +1,2190 @@
+use std::collections::HashMap;
+
+use async_stream::try_stream;
+use chrono::{DateTime, Utc};
+use futures_util::{Stream, TryStreamExt};
+use sha2::{Digest, Sha256};
 use sqlx::PgPool;
 use uuid::Uuid;
 
+use crate::content;
+use crate::email::OutgoingEmail;
+use crate::email::auto_reply::AutoReplyConfig;
+use crate::email::routing::RoutingConfig;
 use crate::errors::{ApiError, ApiResult};
 use crate::models::*;
+use crate::normalize;
+use crate::scoring::{self, Engagement, LeadScore, ScoringConfig};
+
+pub async fn list_blog_posts(
+    pool: &PgPool,
+    category: Option<&str>,
+    tag: Option<&str>,
+    limit: i64,
+    offset: i64,
+) -> ApiResult<Vec<BlogPostSummary>> {
+    // Tags are stored by slug, so `?tag=Cover Crops` finds `cover-crops`
+    let tag = tag.map(content::slugify);
+    let posts = sqlx::query_as!(
+        BlogPostSummary,
+        r#"
+        SELECT p.id, p.slug, p.title, p.subtitle, p.category, p.hero_image_url,
+               p.excerpt, p.author_name, p.read_time_minutes, p.published_at, p.updated_at,
+               ARRAY(
+                   SELECT t.name FROM blog_post_tags pt JOIN blog_tags t ON t.id = pt.tag_id
+                   WHERE pt.post_id = p.id ORDER BY t.name
+               ) as "tags!"
+        FROM blog_posts p
+        WHERE p.published = true AND p.published_at <= NOW()
+          AND ($1::text IS NULL OR LOWER(p.category) = LOWER($1))
+          AND ($2::text IS NULL OR EXISTS (
+              SELECT 1 FROM blog_post_tags pt JOIN blog_tags t ON t.id = pt.tag_id
+              WHERE pt.post_id = p.id AND t.slug = $2
+          ))
+        ORDER BY p.published_at DESC
+        LIMIT $3 OFFSET $4
+        "#,
+        category,
+        tag,
+        limit,
+        offset
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(posts)
+}
+
+/// Ranked full-text search over visible posts. `query` uses web search syntax:
+/// quoted phrases, `or`, and `-excluded` terms.
+pub async fn search_blog_posts(
+    pool: &PgPool,
+    query: &str,
+    limit: i64,
+    offset: i64,
+) -> ApiResult<Vec<BlogSearchResult>> {
+    let rows = sqlx::query!(
+        r#"
+        SELECT p.id, p.slug, p.title, p.subtitle, p.category, p.hero_image_url,
+               p.excerpt, p.author_name, p.read_time_minutes, p.published_at, p.updated_at,
+               ARRAY(
+                   SELECT t.name FROM blog_post_tags pt JOIN blog_tags t ON t.id = pt.tag_id
+                   WHERE pt.post_id = p.id ORDER BY t.name
+               ) as "tags!",
+               ts_headline(
+                   'english',
+                   regexp_replace(p.content_html, '<[^>]*>', ' ', 'g'),
+                   q,
+                   'StartSel=<mark>, StopSel=</mark>, MaxWords=35, MinWords=15, MaxFragments=2'
+               ) as "snippet!",
+               ts_rank(p.search_vector, q) as "rank!"
+        FROM blog_posts p, websearch_to_tsquery('english', $1) q
+        WHERE p.published = true AND p.published_at <= NOW()
+          AND p.search_vector @@ q
+        ORDER BY ts_rank(p.search_vector, q) DESC, p.published_at DESC
+        LIMIT $2 OFFSET $3
+        "#,
+        query,
+        limit,
+        offset,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let results = rows
+        .into_iter()
+        .map(|r| BlogSearchResult {
+            post: BlogPostSummary {
+                id: r.id,
+                slug: r.slug,
+                title: r.title,
+                subtitle: r.subtitle,
+                category: r.category,
+                hero_image_url: r.hero_image_url,
+                excerpt: r.excerpt,
+                author_name: r.author_name,
+                read_time_minutes: r.read_time_minutes,
+                published_at: r.published_at,
+                updated_at: r.updated_at,
+                tags: r.tags,
+            },
+            snippet: r.snippet,
+            rank: r.rank,
+        })
+        .collect();
+    Ok(results)
+}
+
+pub async fn get_blog_post_by_slug(pool: &PgPool, slug: &str) -> ApiResult<BlogPost> {
+    sqlx::query_as!(
+        BlogPost,
+        r#"
+        SELECT id, slug, title, subtitle, category, hero_image_url, content_html, content_markdown,
+               excerpt, author_name, read_time_minutes, published, published_at, created_at, updated_at
+        FROM blog_posts
+        WHERE slug = $1 AND published = true AND published_at <= NOW()
+        "#,
+        slug
+    )
+    .fetch_optional(pool)
+    .await?
+    .ok_or_else(|| ApiError::NotFound(format!("Post '{slug}' not found")))
+}
+
+/// Visible posts most related to `post_id`. Score, highest first:
+/// same category (+3), each shared tag (+2), overlap between the source title and
+/// the candidate's search vector (ts_rank × 10), and a recency term that halves
+/// after ~90 days.
+pub async fn related_blog_posts(
+    pool: &PgPool,
+    post_id: Uuid,
+    limit: i64,
+) -> ApiResult<Vec<BlogPostSummary>> {
+    let posts = sqlx::query_as!(
+        BlogPostSummary,
+        r#"
+        WITH src AS (
+            SELECT id, category,
+                   replace(plainto_tsquery('english', title || ' ' || coalesce(subtitle, ''))::text, '&', '|')::tsquery AS q
+            FROM blog_posts
+            WHERE id = $1
+        )
+        SELECT p.id, p.slug, p.title, p.subtitle, p.category, p.hero_image_url,
+               p.excerpt, p.author_name, p.read_time_minutes, p.published_at, p.updated_at,
+               ARRAY(
+                   SELECT t.name FROM blog_post_tags pt JOIN blog_tags t ON t.id = pt.tag_id
+                   WHERE pt.post_id = p.id ORDER BY t.name
+               ) as "tags!"
+        FROM blog_posts p, src
+        WHERE p.id <> src.id
+          AND p.published = true AND p.published_at <= NOW()
+        ORDER BY
+            (CASE WHEN LOWER(p.category) = LOWER(src.category) THEN 3 ELSE 0 END)
+            + 2 * (
+                SELECT COUNT(*) FROM blog_post_tags a
+                JOIN blog_post_tags b ON b.tag_id = a.tag_id
+                WHERE a.post_id = p.id AND b.post_id = src.id
+            )
+            + 10 * ts_rank(p.search_vector, src.q)
+            + 1.0 / (1 + EXTRACT(EPOCH FROM NOW() - p.published_at) / 86400 / 90)
+            DESC,
+            p.published_at DESC
+        LIMIT $2
+        "#,
+        post_id,
+        limit,
+    )
+    .fetch_all(pool)
+    .await?;
+    Ok(posts)
+}
+
+// ── Blog Admin ───────────────────────────────
+
+pub async fn admin_list_blog_posts(
+    pool: &PgPool,
+    limit: i64,
+    offset: i64,
+) -> ApiResult<Vec<BlogPost>> {
+    let posts = sqlx::query_as!(
+        BlogPost,
+        r#"
+        SELECT id, slug, title, subtitle, category, hero_image_url, content_html, content_markdown,
+               excerpt, author_name, read_time_minutes, published, published_at, created_at, updated_at
+        FROM blog_posts
+        ORDER BY updated_at DESC
+        LIMIT $1 OFFSET $2
+        "#,
+        limit,
+        offset,
+    )
+    .fetch_all(pool)
+    .await?;
+    Ok(posts)
+}
+
+/// Published posts whose `published_at` is still in the future, soonest first.
+pub async fn list_scheduled_blog_posts(pool: &PgPool) -> ApiResult<Vec<BlogPostSummary>> {
+    let posts = sqlx::query_as!(
+        BlogPostSummary,
+        r#"
+        SELECT p.id, p.slug, p.title, p.subtitle, p.category, p.hero_image_url,
+               p.excerpt, p.author_name, p.read_time_minutes, p.published_at, p.updated_at,
+               ARRAY(
+                   SELECT t.name FROM blog_post_tags pt JOIN blog_tags t ON t.id = pt.tag_id
+                   WHERE pt.post_id = p.id ORDER BY t.name
+               ) as "tags!"
+        FROM blog_posts p
+        WHERE p.published = true AND p.published_at > NOW()
+        ORDER BY p.published_at ASC
+        "#,
+    )
+    .fetch_all(pool)
+    .await?;
+    Ok(posts)
+}
+
+pub async fn get_blog_post(pool: &PgPool, id: Uuid) -> ApiResult<BlogPost> {
+    sqlx::query_as!(
+        BlogPost,
+        r#"
+        SELECT id, slug, title, subtitle, category, hero_image_url, content_html, content_markdown,
+               excerpt, author_name, read_time_minutes, published, published_at, created_at, updated_at
+        FROM blog_posts
+        WHERE id = $1
+        "#,
+        id,
+    )
+    .fetch_optional(pool)
+    .await?
+    .ok_or_else(|| ApiError::NotFound(format!("Post {id} not found")))
+}
+
+pub async fn create_blog_post(pool: &PgPool, req: &BlogPostRequest) -> ApiResult<BlogPost> {
+    let rendered = content::render_post(req);
+    let mut tx = pool.begin().await?;
+    let post = sqlx::query_as!(
+        BlogPost,
+        r#"
+        INSERT INTO blog_posts
+            (id, slug, title, subtitle, category, hero_image_url, content_html, content_markdown,
+             excerpt, author_name, read_time_minutes, published, published_at)
+        VALUES ($1, $2, $3, $4, COALESCE($5, 'general'), $6, $7, $8, $9,
+                COALESCE($10, 'Terroir AI Team'), $11, $12,
+                COALESCE($13, CASE WHEN $12 THEN NOW() END))
+        RETURNING id, slug, title, subtitle, category, hero_image_url, content_html, content_markdown,
+                  excerpt, author_name, read_time_minutes, published, published_at, created_at, updated_at
+        "#,
+        Uuid::new_v4(),
+        req.slug,
+        req.title,
+        req.subtitle,
+        req.category,
+        req.hero_image_url,
+        rendered.content_html,
+        req.content_markdown,
+        rendered.excerpt,
+        req.author_name,
+        rendered.read_time_minutes,
+        req.published,
+        req.published_at,
+    )
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|e| slug_conflict(e, &req.slug))?;
+
+    if let Some(tags) = &req.tags {
+        set_blog_post_tags(&mut tx, post.id, tags).await?;
+    }
+    tx.commit().await?;
+    Ok(post)
+}
+
+/// Replace every field of a post with `req`. Fields left out of the request
+/// take the same defaults as on create; they are not kept from the old post.
+pub async fn update_blog_post(
+    pool: &PgPool,
+    id: Uuid,
+    req: &BlogPostRequest,
+) -> ApiResult<BlogPost> {
+    let rendered = content::render_post(req);
+    let mut tx = pool.begin().await?;
+    let post = sqlx::query_as!(
+        BlogPost,
+        r#"
+        UPDATE blog_posts SET
+            slug = $2,
+            title = $3,
+            subtitle = $4,
+            category = COALESCE($5, 'general'),
+            hero_image_url = $6,
+            content_html = $7,
+            content_markdown = $8,
+            excerpt = $9,
+            author_name = COALESCE($10, 'Terroir AI Team'),
+            read_time_minutes = $11,
+            published = $12,
+            published_at = COALESCE($13, CASE WHEN $12 THEN NOW() END),
+            updated_at = NOW()
+        WHERE id = $1
+        RETURNING id, slug, title, subtitle, category, hero_image_url, content_html, content_markdown,
+                  excerpt, author_name, read_time_minutes, published, published_at, created_at, updated_at
+        "#,
+        id,
+        req.slug,
+        req.title,
+        req.subtitle,
+        req.category,
+        req.hero_image_url,
+        rendered.content_html,
+        req.content_markdown,
+        rendered.excerpt,
+        req.author_name,
+        rendered.read_time_minutes,
+        req.published,
+        req.published_at,
+    )
+    .fetch_optional(&mut *tx)
+    .await
+    .map_err(|e| slug_conflict(e, &req.slug))?
+    .ok_or_else(|| ApiError::NotFound(format!("Post {id} not found")))?;
+
+    set_blog_post_tags(&mut tx, post.id, req.tags.as_deref().unwrap_or_default()).await?;
+    tx.commit().await?;
+    Ok(post)
+}
+
+/// Flip a post between draft and published. The first publish stamps `published_at`;
+/// unpublishing keeps it so a re-publish doesn't move the post in the listing.
+pub async fn set_blog_post_published(
+    pool: &PgPool,
+    id: Uuid,
+    published: bool,
+) -> ApiResult<BlogPost> {
+    sqlx::query_as!(
+        BlogPost,
+        r#"
+        UPDATE blog_posts SET
+            published = $2,
+            published_at = COALESCE(published_at, CASE WHEN $2 THEN NOW() END),
+            updated_at = NOW()
+        WHERE id = $1
+        RETURNING id, slug, title, subtitle, category, hero_image_url, content_html, content_markdown,
+                  excerpt, author_name, read_time_minutes, published, published_at, created_at, updated_at
+        "#,
+        id,
+        published,
+    )
+    .fetch_optional(pool)
+    .await?
+    .ok_or_else(|| ApiError::NotFound(format!("Post {id} not found")))
+}
+
+/// Delete a post. Its revisions are kept under `deleted_post_id`, so it can be
+/// found and restored with `restore_deleted_blog_post`.
+pub async fn delete_blog_post(pool: &PgPool, id: Uuid) -> ApiResult<()> {
+    let mut tx = pool.begin().await?;
+    sqlx::query!(
+        r#"UPDATE blog_post_revisions SET deleted_post_id = post_id WHERE post_id = $1"#,
+        id,
+    )
+    .execute(&mut *tx)
+    .await?;
+    let result = sqlx::query!(r#"DELETE FROM blog_posts WHERE id = $1"#, id)
+        .execute(&mut *tx)
+        .await?;
+    if result.rows_affected() == 0 {
+        return Err(ApiError::NotFound(format!("Post {id} not found")));
+    }
+    tx.commit().await?;
+    Ok(())
+}
+
+// ── Blog Revisions ───────────────────────────
+
+pub async fn list_blog_post_revisions(
+    pool: &PgPool,
+    post_id: Uuid,
+) -> ApiResult<Vec<BlogPostRevisionSummary>> {
+    let rows = sqlx::query_as!(
+        BlogPostRevisionSummary,
+        r#"
+        SELECT id, revision_number, slug, title, published, created_at
+        FROM blog_post_revisions
+        WHERE post_id = $1
+        ORDER BY revision_number DESC
+        "#,
+        post_id,
+    )
+    .fetch_all(pool)
+    .await?;
+    Ok(rows)
+}
+
+pub async fn get_blog_post_revision(
+    db: impl sqlx::PgExecutor<'_>,
+    post_id: Uuid,
+    revision: i32,
+) -> ApiResult<BlogPostRevision> {
+    sqlx::query_as!(
+        BlogPostRevision,
+        r#"SELECT * FROM blog_post_revisions WHERE post_id = $1 AND revision_number = $2"#,
+        post_id,
+        revision,
+    )
+    .fetch_optional(db)
+    .await?
+    .ok_or_else(|| ApiError::NotFound(format!("Revision {revision} of post {post_id} not found")))
+}
+
+/// Copy an old revision's copy and tags back onto the post. Slug and publish state
+/// are left as they are so a rollback never moves or unpublishes a live URL. The
+/// revision trigger records the restore as a new revision.
+pub async fn restore_blog_post_revision(
+    pool: &PgPool,
+    post_id: Uuid,
+    revision: i32,
+) -> ApiResult<BlogPost> {
+    let mut tx = pool.begin().await?;
+    let rev = get_blog_post_revision(&mut *tx, post_id, revision).await?;
+    let post = sqlx::query_as!(
+        BlogPost,
+        r#"
+        UPDATE blog_posts SET
+            title = $2,
+            subtitle = $3,
+            category = $4,
+            hero_image_url = $5,
+            content_html = $6,
+            content_markdown = $7,
+            excerpt = $8,
+            author_name = $9,
+            read_time_minutes = $10,
+            updated_at = NOW()
+        WHERE id = $1
+        RETURNING id, slug, title, subtitle, category, hero_image_url, content_html, content_markdown,
+                  excerpt, author_name, read_time_minutes, published, published_at, created_at, updated_at
+        "#,
+        post_id,
+        rev.title,
+        rev.subtitle,
+        rev.category,
+        rev.hero_image_url,
+        rev.content_html,
+        rev.content_markdown,
+        rev.excerpt,
+        rev.author_name,
+        rev.read_time_minutes,
+    )
+    .fetch_optional(&mut *tx)
+    .await?
+    .ok_or_else(|| ApiError::NotFound(format!("Post {post_id} not found")))?;
+
+    // Revisions from before tags were recorded leave the current tags alone
+    if let Some(tags) = &rev.tags {
+        set_blog_post_tags(&mut tx, post_id, tags).await?;
+    }
+    tx.commit().await?;
+    Ok(post)
+}
+
+/// Deleted posts that still have revisions, most recently edited first.
+pub async fn list_deleted_blog_posts(pool: &PgPool) -> ApiResult<Vec<DeletedBlogPost>> {
+    let rows = sqlx::query_as!(
+        DeletedBlogPost,
+        r#"
+        SELECT id as "id!", slug as "slug!", title as "title!", revision_count as "revision_count!",
+               latest_revision as "latest_revision!", last_revised_at as "last_revised_at!"
+        FROM (
+            SELECT DISTINCT ON (deleted_post_id)
+                deleted_post_id AS id, slug, title,
+                COUNT(*) OVER (PARTITION BY deleted_post_id) AS revision_count,
+                revision_number AS latest_revision,
+                created_at AS last_revised_at
+            FROM blog_post_revisions
+            WHERE deleted_post_id IS NOT NULL
+            ORDER BY deleted_post_id, revision_number DESC
+        ) latest
+        ORDER BY last_revised_at DESC
+        "#
+    )
+    .fetch_all(pool)
+    .await?;
+    Ok(rows)
+}
+
+pub async fn list_deleted_blog_post_revisions(
+    pool: &PgPool,
+    deleted_post_id: Uuid,
+) -> ApiResult<Vec<BlogPostRevisionSummary>> {
+    let rows = sqlx::query_as!(
+        BlogPostRevisionSummary,
+        r#"
+        SELECT id, revision_number, slug, title, published, created_at
+        FROM blog_post_revisions
+        WHERE deleted_post_id = $1
+        ORDER BY revision_number DESC
+        "#,
+        deleted_post_id,
+    )
+    .fetch_all(pool)
+    .await?;
+    if rows.is_empty() {
+        return Err(ApiError::NotFound(format!(
+            "Deleted post {deleted_post_id} not found"
+        )));
+    }
+    Ok(rows)
+}
+
+/// Bring a deleted post back under its old id from one of its revisions, with
+/// its history reattached. It comes back as a draft, so nothing goes live
+/// again until an admin publishes it; the slug must still be free.
+pub async fn restore_deleted_blog_post(
+    pool: &PgPool,
+    deleted_post_id: Uuid,
+    revision: i32,
+) -> ApiResult<BlogPost> {
+    let mut tx = pool.begin().await?;
+    let rev = sqlx::query_as!(
+        BlogPostRevision,
+        r#"SELECT * FROM blog_post_revisions WHERE deleted_post_id = $1 AND revision_number = $2"#,
+        deleted_post_id,
+        revision,
+    )
+    .fetch_optional(&mut *tx)
+    .await?
+    .ok_or_else(|| {
+        ApiError::NotFound(format!(
+            "Revision {revision} of deleted post {deleted_post_id} not found"
+        ))
+    })?;
+
+    let post = sqlx::query_as!(
+        BlogPost,
+        r#"
+        INSERT INTO blog_posts
+            (id, slug, title, subtitle, category, hero_image_url, content_html, content_markdown,
+             excerpt, author_name, read_time_minutes, published, published_at)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, FALSE, NULL)
+        RETURNING id, slug, title, subtitle, category, hero_image_url, content_html, content_markdown,
+                  excerpt, author_name, read_time_minutes, published, published_at, created_at, updated_at
+        "#,
+        deleted_post_id,
+        rev.slug,
+        rev.title,
+        rev.subtitle,
+        rev.category,
+        rev.hero_image_url,
+        rev.content_html,
+        rev.content_markdown,
+        rev.excerpt,
+        rev.author_name,
+        rev.read_time_minutes,
+    )
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|e| slug_conflict(e, &rev.slug))?;
+
+    // Reattached before commit, so the revision the trigger records for the
+    // restore numbers on from the old history
+    sqlx::query!(
+        r#"
+        UPDATE blog_post_revisions SET post_id = $1, deleted_post_id = NULL
+        WHERE deleted_post_id = $1
+        "#,
+        deleted_post_id,
+    )
+    .execute(&mut *tx)
+    .await?;
+    if let Some(tags) = &rev.tags {
+        set_blog_post_tags(&mut tx, post.id, tags).await?;
+    }
+    tx.commit().await?;
+    Ok(post)
+}
+
+fn slug_conflict(e: sqlx::Error, slug: &str) -> ApiError {
+    match &e {
+        sqlx::Error::Database(db) if db.is_unique_violation() => {
+            ApiError::Conflict(format!("A post with slug '{slug}' already exists"))
+        }
+        _ => ApiError::Database(e),
+    }
+}
+
+// ── Blog Metrics ─────────────────────────────
+
+/// Views, readers, scroll depth and downstream conversions per post for
+/// `[from, to)`. Page views are matched on path with any query string dropped.
+pub async fn blog_post_metrics(
+    pool: &PgPool,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+) -> ApiResult<Vec<BlogPostMetrics>> {
+    let rows = sqlx::query_as!(
+        BlogPostMetrics,
+        r#"
+        WITH readers AS (
+            SELECT split_part(path, '?', 1) AS path, visitor_id,
+                   COUNT(*) AS views, MIN(created_at) AS first_view
+            FROM analytics_page_views
+            WHERE created_at >= $1 AND created_at < $2 AND path LIKE '/blog/%'
+            GROUP BY 1, 2
+        ),
+        depth AS (
+            SELECT event_label AS slug, visitor_id, MAX(event_value) AS depth
+            FROM analytics_events
+            WHERE event_name = 'blog_read' AND created_at >= $1 AND created_at < $2
+            GROUP BY 1, 2
+        ),
+        conversions AS (
+            SELECT visitor_id,
+                   MIN(created_at) FILTER (WHERE event_name = 'contact_submit') AS contacted_at,
+                   MIN(created_at) FILTER (WHERE event_name IN ('waitlist_join', 'pilot_signup')) AS joined_at
+            FROM analytics_events
+            WHERE event_name IN ('contact_submit', 'waitlist_join', 'pilot_signup')
+              AND created_at >= $1 AND created_at < $2
+            GROUP BY visitor_id
+        )
+        SELECT p.id as post_id, p.slug, p.title, p.published_at,
+               COALESCE(SUM(r.views), 0)::bigint as "views!",
+               COUNT(r.visitor_id) as "unique_visitors!",
+               (SELECT AVG(d.depth) FROM depth d WHERE d.slug = p.slug) as avg_scroll_depth,
+               COUNT(r.visitor_id) FILTER (WHERE c.contacted_at >= r.first_view) as "contact_conversions!",
+               COUNT(r.visitor_id) FILTER (WHERE c.joined_at >= r.first_view) as "waitlist_conversions!"
+        FROM blog_posts p
+        LEFT JOIN readers r ON r.path = '/blog/' || p.slug
+        LEFT JOIN conversions c ON c.visitor_id = r.visitor_id
+        GROUP BY p.id
+        ORDER BY 5 DESC, p.published_at DESC
+        "#,
+        from,
+        to,
+    )
+    .fetch_all(pool)
+    .await?;
+    Ok(rows)
+}
+
+// ── Sitemap ──────────────────────────────────
+
+pub async fn sitemap_stats(pool: &PgPool) -> ApiResult<SitemapStats> {
+    let stats = sqlx::query_as!(
+        SitemapStats,
+        r#"
+        SELECT COUNT(*) as "post_count!",
+               MAX(GREATEST(updated_at, published_at)) as last_modified
+        FROM blog_posts
+        WHERE published = true AND published_at <= NOW()
+        "#,
+    )
+    .fetch_one(pool)
+    .await?;
+    Ok(stats)
+}
+
+pub async fn list_sitemap_posts(
+    pool: &PgPool,
+    limit: i64,
+    offset: i64,
+) -> ApiResult<Vec<SitemapPost>> {
+    let posts = sqlx::query_as!(
+        SitemapPost,
+        r#"
+        SELECT slug, updated_at
+        FROM blog_posts
+        WHERE published = true AND published_at <= NOW()
+        ORDER BY published_at DESC, id
+        LIMIT $1 OFFSET $2
+        "#,
+        limit,
+        offset,
+    )
+    .fetch_all(pool)
+    .await?;
+    Ok(posts)
+}
+
+// ── Blog Taxonomy ────────────────────────────
+
+/// Categories with at least one visible post.
+pub async fn list_blog_categories(pool: &PgPool) -> ApiResult<Vec<BlogCategory>> {
+    let rows = sqlx::query_as!(
+        BlogCategory,
+        r#"
+        SELECT category, COUNT(*) as "post_count!"
+        FROM blog_posts
+        WHERE published = true AND published_at <= NOW()
+        GROUP BY category
+        ORDER BY COUNT(*) DESC, category
+        "#,
+    )
+    .fetch_all(pool)
+    .await?;
+    Ok(rows)
+}
+
+/// Tags with at least one visible post.
+pub async fn list_blog_tags(pool: &PgPool) -> ApiResult<Vec<BlogTag>> {
+    let rows = sqlx::query_as!(
+        BlogTag,
+        r#"
+        SELECT t.slug, t.name, COUNT(*) as "post_count!"
+        FROM blog_tags t
+        JOIN blog_post_tags pt ON pt.tag_id = t.id
+        JOIN blog_posts p ON p.id = pt.post_id
+        WHERE p.published = true AND p.published_at <= NOW()
+        GROUP BY t.slug, t.name
+        ORDER BY COUNT(*) DESC, t.name
+        "#,
+    )
+    .fetch_all(pool)
+    .await?;
+    Ok(rows)
+}
+
+pub async fn get_blog_post_tags(pool: &PgPool, post_id: Uuid) -> ApiResult<Vec<String>> {
+    let tags = sqlx::query_scalar!(
+        r#"
+        SELECT t.name
+        FROM blog_post_tags pt JOIN blog_tags t ON t.id = pt.tag_id
+        WHERE pt.post_id = $1
+        ORDER BY t.name
+        "#,
+        post_id,
+    )
+    .fetch_all(pool)
+    .await?;
+    Ok(tags)
+}
+
+pub async fn blog_post_detail(pool: &PgPool, post: BlogPost) -> ApiResult<BlogPostDetail> {
+    let tags = get_blog_post_tags(pool, post.id).await?;
+    Ok(BlogPostDetail { post, tags })
+}
+
+/// Replace a post's tags, creating any tag that doesn't exist yet. Tags are
+/// matched by slug, so "H-2A" and "h-2a" are one tag; the first spelling seen
+/// is kept as the display name.
+async fn set_blog_post_tags(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    post_id: Uuid,
+    tags: &[String],
+) -> ApiResult<()> {
+    let mut slugs: Vec<String> = Vec::with_capacity(tags.len());
+    let mut names: Vec<String> = Vec::with_capacity(tags.len());
+    for name in tags {
+        let slug = content::slugify(name);
+        if !slug.is_empty() && !slugs.contains(&slug) {
+            slugs.push(slug);
+            names.push(name.trim().to_string());
+        }
+    }
+
+    sqlx::query!(r#"DELETE FROM blog_post_tags WHERE post_id = $1"#, post_id)
+        .execute(&mut **tx)
+        .await?;
+    sqlx::query!(
+        r#"
+        INSERT INTO blog_tags (slug, name)
+        SELECT * FROM UNNEST($1::text[], $2::text[])
+        ON CONFLICT (slug) DO NOTHING
+        "#,
+        &slugs,
+        &names,
+    )
+    .execute(&mut **tx)
+    .await?;
+    sqlx::query!(
+        r#"
+        INSERT INTO blog_post_tags (post_id, tag_id)
+        SELECT $1, id FROM blog_tags WHERE slug = ANY($2)
+        "#,
+        post_id,
+        &slugs,
+    )
+    .execute(&mut **tx)
+    .await?;
+    Ok(())
+}
+
+/// Insert a contact submission in the caller's transaction, so the emails it
+/// triggers are queued with it or not at all.
+pub async fn create_contact(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    req: &ContactRequest,
+) -> ApiResult<ContactSubmission> {
+    let person_id = upsert_person(tx, &req.email, Some(&req.name), req.company.as_deref()).await?;
+
+    let acreage = req.acreage.as_deref().and_then(normalize::parse_acreage);
+    let submission = sqlx::query_as!(
+        ContactSubmission,
+        r#"
+        INSERT INTO contact_submissions
+            (id, name, email, company, phone, acreage, crop_type, message, source, person_id, visitor_id,
+             acreage_value, acreage_unit, crop_type_normalized)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14)
+        RETURNING *
+        "#,
+        Uuid::new_v4(),
+        req.name,
+        req.email,
+        req.company,
+        req.phone,
+        req.acreage,
+        req.crop_type,
+        req.message,
+        req.source.clone().unwrap_or_else(|| "website".to_string()),
+        person_id,
+        req.visitor_id,
+        acreage.map(|a| a.value),
+        acreage.map(|a| a.unit.as_str()),
+        req.crop_type.as_deref().and_then(normalize::normalize_crop),
+    )
+    .fetch_one(&mut **tx)
+    .await?;
+    Ok(submission)
+}
+
+/// Insert or refresh a waitlist signup in the caller's transaction, like
+/// `create_contact`. Once an entry is confirmed, a repeat signup can't change
+/// its details; before that, the latest form wins.
+pub async fn create_waitlist_entry(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    req: &WaitlistRequest,
+) -> ApiResult<WaitlistEntry> {
+    let person_id =
+        upsert_person(tx, &req.email, req.name.as_deref(), req.company.as_deref()).await?;
+
+    // A code that matches nothing, or the subscriber's own, is ignored
+    let referred_by = match req.ref_code.as_deref() {
+        Some(code) => sqlx::query_scalar!(
+            r#"SELECT id FROM waitlist_entries WHERE referral_code = UPPER($1) AND LOWER(email) <> LOWER($2)"#,
+            code.trim(),
+            req.email,
+        )
+        .fetch_optional(&mut **tx)
+        .await?,
+        None => None,
+    };
+
+    let entry = sqlx::query_as!(
+        WaitlistEntry,
+        r#"
+        INSERT INTO waitlist_entries
+            (id, email, name, company, interest, person_id, visitor_id, referral_code, referred_by)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+        ON CONFLICT (email, interest) DO UPDATE SET
+            name = CASE WHEN waitlist_entries.confirmed_at IS NULL
+                        THEN EXCLUDED.name ELSE waitlist_entries.name END,
+            company = CASE WHEN waitlist_entries.confirmed_at IS NULL
+                           THEN EXCLUDED.company ELSE waitlist_entries.company END,
+            person_id = EXCLUDED.person_id,
+            visitor_id = CASE WHEN waitlist_entries.confirmed_at IS NULL
+                              THEN COALESCE(EXCLUDED.visitor_id, waitlist_entries.visitor_id)
+                              ELSE waitlist_entries.visitor_id END,
+            referred_by = COALESCE(waitlist_entries.referred_by, EXCLUDED.referred_by)
+        RETURNING *
+        "#,
+        Uuid::new_v4(),
+        req.email,
+        req.name,
+        req.company,
+        req.interest
+            .clone()
+            .unwrap_or_else(|| "general".to_string()),
+        person_id,
+        req.visitor_id,
+        new_referral_code(),
+        referred_by,
+    )
+    .fetch_one(&mut **tx)
+    .await?;
+    Ok(entry)
+}
+
+/// Ten characters from an alphabet without look-alikes (0/O, 1/I/L): about
+/// 50 bits, so collisions are not a practical concern at waitlist scale.
+fn new_referral_code() -> String {
+    const ALPHABET: &[u8] = b"23456789ABCDEFGHJKMNPQRSTUVWXYZ";
+    Uuid::new_v4().as_bytes()[..10]
+        .iter()
+        .map(|b| ALPHABET[*b as usize % ALPHABET.len()] as char)
+        .collect()
+}
+
+pub async fn waitlist_status(pool: &PgPool, referral_code: &str) -> ApiResult<WaitlistStatus> {
+    let status = sqlx::query_as!(
+        WaitlistStatus,
+        r#"
+        SELECT w.referral_code, w.interest,
+               w.confirmed_at IS NOT NULL as "confirmed!",
+               CASE WHEN w.confirmed_at IS NOT NULL THEN (
+                   SELECT COUNT(*) FROM waitlist_entries q
+                   WHERE q.interest = w.interest AND q.confirmed_at IS NOT NULL
+                     AND (q.created_at, q.id) <= (w.created_at, w.id)
+               ) END as position,
+               (SELECT COUNT(*) FROM waitlist_entries r
+                WHERE r.referred_by = w.id AND r.confirmed_at IS NOT NULL) as "referral_count!"
+        FROM waitlist_entries w
+        WHERE w.referral_code = UPPER($1)
+        "#,
+        referral_code.trim(),
+    )
+    .fetch_optional(pool)
+    .await?
+    .ok_or_else(|| ApiError::NotFound(format!("Referral code {referral_code} not found")))?;
+    Ok(status)
+}
+
+/// Entries ranked by confirmed referrals, for rewarding top referrers.
+pub async fn referral_leaderboard(
+    pool: &PgPool,
+    interest: Option<&str>,
+    limit: i64,
+) -> ApiResult<Vec<ReferralLeader>> {
+    let rows = sqlx::query_as!(
+        ReferralLeader,
+        r#"
+        SELECT w.id, w.email, w.name, w.company, w.interest, w.referral_code,
+               COUNT(r.id) FILTER (WHERE r.confirmed_at IS NOT NULL) as "referral_count!",
+               COUNT(r.id) FILTER (WHERE r.confirmed_at IS NULL) as "pending_referrals!"
+        FROM waitlist_entries w
+        JOIN waitlist_entries r ON r.referred_by = w.id
+        WHERE ($1::text IS NULL OR w.interest = $1)
+        GROUP BY w.id
+        ORDER BY 7 DESC, 8 DESC, w.created_at
+        LIMIT $2
+        "#,
+        interest,
+        limit,
+    )
+    .fetch_all(pool)
+    .await?;
+    Ok(rows)
+}
+
+/// Record that a confirmation email is going out, unless the entry is already
+/// confirmed or one went out in the last few minutes (repeat form posts must
+/// not turn us into a mail cannon). Returns whether the caller should send.
+pub async fn claim_waitlist_confirmation(
+    db: impl sqlx::PgExecutor<'_>,
+    id: Uuid,
+) -> ApiResult<bool> {
+    let claimed = sqlx::query_scalar!(
+        r#"
+        UPDATE waitlist_entries SET confirmation_sent_at = NOW()
+        WHERE id = $1
+          AND confirmed_at IS NULL
+          AND (confirmation_sent_at IS NULL OR confirmation_sent_at < NOW() - INTERVAL '10 minutes')
+        RETURNING id
+        "#,
+        id,
+    )
+    .fetch_optional(db)
+    .await?;
+    Ok(claimed.is_some())
+}
+
+/// Mark an entry confirmed. The flag is true only for the click that actually
+/// confirmed it, so follow-up work runs once.
+pub async fn confirm_waitlist_entry(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    id: Uuid,
+) -> ApiResult<(WaitlistEntry, bool)> {
+    let confirmed = sqlx::query_as!(
+        WaitlistEntry,
+        r#"
+        UPDATE waitlist_entries SET confirmed_at = NOW()
+        WHERE id = $1 AND confirmed_at IS NULL
+        RETURNING *
+        "#,
+        id,
+    )
+    .fetch_optional(&mut **tx)
+    .await?;
+    if let Some(entry) = confirmed {
+        // Confirming a fresh signup is an explicit opt-in again
+        sqlx::query!(
+            r#"UPDATE people SET unsubscribed_at = NULL WHERE id = $1"#,
+            entry.person_id,
+        )
+        .execute(&mut **tx)
+        .await?;
+        return Ok((entry, true));
+    }
+
+    let entry = sqlx::query_as!(
+        WaitlistEntry,
+        r#"SELECT * FROM waitlist_entries WHERE id = $1"#,
+        id
+    )
+    .fetch_optional(&mut **tx)
+    .await?
+    .ok_or_else(|| ApiError::NotFound(format!("Waitlist entry {id} not found")))?;
+    Ok((entry, false))
+}
+
+// ── Pilot Cohorts ────────────────────────────
+
+pub async fn create_cohort(pool: &PgPool, req: &PilotCohortRequest) -> ApiResult<PilotCohort> {
+    let cohort = sqlx::query_as!(
+        PilotCohort,
+        r#"
+        INSERT INTO pilot_cohorts (name, interest, region, crop_focus, capacity, starts_on)
+        VALUES ($1, COALESCE($2, 'pilot_2026'), $3, $4, $5, $6)
+        ON CONFLICT (name) DO NOTHING
+        RETURNING *
+        "#,
+        req.name,
+        req.interest,
+        req.region,
+        req.crop_focus,
+        req.capacity,
+        req.starts_on,
+    )
+    .fetch_optional(pool)
+    .await?;
+    cohort
+        .ok_or_else(|| ApiError::Conflict(format!("A cohort named '{}' already exists", req.name)))
+}
+
+/// Replace a cohort's settings. Capacity can't drop below the seats already
+/// held by invited and accepted entries.
+pub async fn update_cohort(
+    pool: &PgPool,
+    id: Uuid,
+    req: &PilotCohortRequest,
+) -> ApiResult<PilotCohort> {
+    let mut tx = pool.begin().await?;
+    sqlx::query_scalar!(
+        r#"SELECT id FROM pilot_cohorts WHERE id = $1 FOR UPDATE"#,
+        id
+    )
+    .fetch_optional(&mut *tx)
+    .await?
+    .ok_or_else(|| ApiError::NotFound(format!("Cohort {id} not found")))?;
+
+    let seats_taken = cohort_seats_taken(&mut tx, id, None).await?;
+    if i64::from(req.capacity) < seats_taken {
+        return Err(ApiError::Validation(format!(
+            "Capacity {} is below the {seats_taken} seats already taken",
+            req.capacity
+        )));
+    }
+
+    let cohort = sqlx::query_as!(
+        PilotCohort,
+        r#"
+        UPDATE pilot_cohorts
+        SET name = $2, interest = COALESCE($3, interest), region = $4, crop_focus = $5,
+            capacity = $6, starts_on = $7, updated_at = NOW()
+        WHERE id = $1
+        RETURNING *
+        "#,
+        id,
+        req.name,
+        req.interest,
+        req.region,
+        req.crop_focus,
+        req.capacity,
+        req.starts_on,
+    )
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|e| match e {
+        sqlx::Error::Database(db) if db.is_unique_violation() => {
+            ApiError::Conflict(format!("A cohort named '{}' already exists", req.name))
+        }
+        e => e.into(),
+    })?;
+
+    tx.commit().await?;
+    Ok(cohort)
+}
+
+/// Every cohort with its seat counts, soonest start first.
+pub async fn cohort_fill_rates(pool: &PgPool) -> ApiResult<Vec<CohortFill>> {
+    let cohorts = sqlx::query_as!(
+        PilotCohort,
+        r#"SELECT * FROM pilot_cohorts ORDER BY starts_on ASC NULLS LAST, name"#
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let counts = sqlx::query!(
+        r#"
+        SELECT cohort_id as "cohort_id!", pilot_status, COUNT(*) as "count!"
+        FROM waitlist_entries
+        WHERE cohort_id IS NOT NULL
+        GROUP BY cohort_id, pilot_status
+        "#
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let fills = cohorts
+        .into_iter()
+        .map(|cohort| {
+            let count = |status: PilotStatus| {
+                counts
+                    .iter()
+                    .find(|row| row.cohort_id == cohort.id && row.pilot_status == status.as_str())
+                    .map_or(0, |row| row.count)
+            };
+            let invited = count(PilotStatus::Invited);
+            let accepted = count(PilotStatus::Accepted);
+            let capacity = i64::from(cohort.capacity);
+            CohortFill {
+                invited,
+                accepted,
+                declined: count(PilotStatus::Declined),
+                waitlisted: count(PilotStatus::Waitlisted),
+                seats_remaining: (capacity - invited - accepted).max(0),
+                fill_rate: accepted as f64 / capacity as f64,
+                cohort,
+            }
+        })
+        .collect();
+    Ok(fills)
+}
+
+/// Invited and accepted entries in a cohort, optionally leaving one out.
+async fn cohort_seats_taken(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    cohort_id: Uuid,
+    except: Option<Uuid>,
+) -> ApiResult<i64> {
+    let taken = sqlx::query_scalar!(
+        r#"
+        SELECT COUNT(*) as "count!" FROM waitlist_entries
+        WHERE cohort_id = $1 AND pilot_status IN ('invited', 'accepted')
+          AND ($2::uuid IS NULL OR id <> $2)
+        "#,
+        cohort_id,
+        except,
+    )
+    .fetch_one(&mut **tx)
+    .await?;
+    Ok(taken)
+}
+
+/// Move a pilot entry to `req.status`, recording the change in the same
+/// transaction. The cohort row is locked while seats are counted so two
+/// admins can't both fill the last seat.
+pub async fn set_pilot_status(
+    pool: &PgPool,
+    id: Uuid,
+    req: &PilotStatusRequest,
+) -> ApiResult<(WaitlistEntry, Option<PilotCohort>)> {
+    let mut tx = pool.begin().await?;
+    let current = sqlx::query!(
+        r#"SELECT pilot_status, cohort_id, interest, confirmed_at FROM waitlist_entries WHERE id = $1 FOR UPDATE"#,
+        id,
+    )
+    .fetch_optional(&mut *tx)
+    .await?
+    .ok_or_else(|| ApiError::NotFound(format!("Waitlist entry {id} not found")))?;
+
+    let from = PilotStatus::parse(&current.pilot_status).ok_or_else(|| {
+        ApiError::Internal(anyhow::anyhow!(
+            "Unknown pilot status '{}'",
+            current.pilot_status
+        ))
+    })?;
+    if !from.can_transition_to(req.status) {
+        return Err(ApiError::Validation(format!(
+            "Cannot move entry from {} to {}",
+            from.as_str(),
+            req.status.as_str()
+        )));
+    }
+
+    let cohort_id = req.cohort_id.or(current.cohort_id);
+    if req.status.holds_seat() {
+        if cohort_id.is_none() {
+            return Err(ApiError::Validation(format!(
+                "A cohort is required to mark an entry {}",
+                req.status.as_str()
+            )));
+        }
+        if current.confirmed_at.is_none() {
+            return Err(ApiError::Validation(
+                "Entry has not confirmed their email yet".to_string(),
+            ));
+        }
+    }
+
+    let cohort = match cohort_id {
+        Some(cohort_id) => {
+            let cohort = sqlx::query_as!(
+                PilotCohort,
+                r#"SELECT * FROM pilot_cohorts WHERE id = $1 FOR UPDATE"#,
+                cohort_id,
+            )
+            .fetch_optional(&mut *tx)
+            .await?
+            .ok_or_else(|| ApiError::NotFound(format!("Cohort {cohort_id} not found")))?;
+            if cohort.interest != current.interest {
+                return Err(ApiError::Validation(format!(
+                    "Entry signed up for {}, but cohort {} is for {}",
+                    current.interest, cohort.name, cohort.interest
+                )));
+            }
+            Some(cohort)
+        }
+        None => None,
+    };
+
+    if let Some(cohort) = &cohort
+        && req.status.holds_seat()
+        && !(from.holds_seat() && current.cohort_id == Some(cohort.id))
+        && cohort_seats_taken(&mut tx, cohort.id, Some(id)).await? >= i64::from(cohort.capacity)
+    {
+        return Err(ApiError::Conflict(format!(
+            "Cohort {} is full ({} seats)",
+            cohort.name, cohort.capacity
+        )));
+    }
+
+    let entry = sqlx::query_as!(
+        WaitlistEntry,
+        r#"
+        UPDATE waitlist_entries SET pilot_status = $2, cohort_id = $3, pilot_status_changed_at = NOW()
+        WHERE id = $1
+        RETURNING *
+        "#,
+        id,
+        req.status.as_str(),
+        cohort_id,
+    )
+    .fetch_one(&mut *tx)
+    .await?;
+
+    sqlx::query!(
+        r#"
+        INSERT INTO pilot_status_history (entry_id, cohort_id, from_status, to_status, changed_by)
+        VALUES ($1, $2, $3, $4, $5)
+        "#,
+        id,
+        cohort_id,
+        from.as_str(),
+        req.status.as_str(),
+        req.changed_by,
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+    Ok((entry, cohort))
+}
+
+pub async fn pilot_entry_detail(
+    pool: &PgPool,
+    entry: WaitlistEntry,
+    cohort: Option<PilotCohort>,
+) -> ApiResult<PilotEntryDetail> {
+    let status_history = sqlx::query_as!(
+        PilotStatusChange,
+        r#"SELECT * FROM pilot_status_history WHERE entry_id = $1 ORDER BY created_at DESC"#,
+        entry.id,
+    )
+    .fetch_all(pool)
+    .await?;
+    let emails = emails_about(pool, EmailAbout::WaitlistEntry(entry.id)).await?;
+    Ok(PilotEntryDetail {
+        entry,
+        cohort,
+        status_history,
+        emails,
+    })
+}
+
+// ── People ───────────────────────────────────
+
+/// The identity key for a person: surrounding whitespace dropped, lowercased.
+pub fn normalize_email(email: &str) -> String {
+    email.trim().to_lowercase()
+}
+
+/// Find or create the person for `email` and bump their last-seen time. Name
+/// and company only fill in blanks: anyone can type any address into a form,
+/// so an unverified submission must not rewrite what's on file. Visitor IDs
+/// are linked separately, by `link_visitor`, once the address is verified.
+async fn upsert_person(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    email: &str,
+    name: Option<&str>,
+    company: Option<&str>,
+) -> ApiResult<Uuid> {
+    let person_id = sqlx::query_scalar!(
+        r#"
+        INSERT INTO people (email, name, company)
+        VALUES ($1, $2, $3)
+        ON CONFLICT (email) DO UPDATE SET
+            name = COALESCE(people.name, EXCLUDED.name),
+            company = COALESCE(people.company, EXCLUDED.company),
+            last_seen_at = NOW()
+        RETURNING id
+        "#,
+        normalize_email(email),
+        name,
+        company,
+    )
+    .fetch_one(&mut **tx)
+    .await?;
+    Ok(person_id)
+}
+
+/// Tie an analytics visitor ID to a person. Only call this after the person
+/// has proved they own the address, e.g. by opening a signed link.
+pub async fn link_visitor(
+    db: impl sqlx::PgExecutor<'_>,
+    person_id: Uuid,
+    visitor_id: &str,
+) -> ApiResult<()> {
+    sqlx::query!(
+        r#"
+        INSERT INTO person_visitors (person_id, visitor_id)
+        VALUES ($1, $2)
+        ON CONFLICT DO NOTHING
+        "#,
+        person_id,
+        visitor_id,
+    )
+    .execute(db)
+    .await?;
+    Ok(())
+}
+
+pub async fn get_person(pool: &PgPool, id: Uuid) -> ApiResult<Person> {
+    sqlx::query_as!(Person, r#"SELECT * FROM people WHERE id = $1"#, id)
+        .fetch_optional(pool)
+        .await?
+        .ok_or_else(|| ApiError::NotFound(format!("Person {id} not found")))
+}
+
+pub async fn find_person_by_email(pool: &PgPool, email: &str) -> ApiResult<Person> {
+    sqlx::query_as!(
+        Person,
+        r#"SELECT * FROM people WHERE email = $1"#,
+        normalize_email(email)
+    )
+    .fetch_optional(pool)
+    .await?
+    .ok_or_else(|| ApiError::NotFound(format!("No person with email '{email}'")))
+}
+
+/// Everything tied to a person, newest first: submissions, lead activity, and
+/// page views and events from every linked visitor ID.
+pub async fn person_timeline(
+    pool: &PgPool,
+    person: Person,
+    limit: i64,
+) -> ApiResult<PersonTimeline> {
+    let visitor_ids = sqlx::query_scalar!(
+        r#"SELECT visitor_id FROM person_visitors WHERE person_id = $1 ORDER BY linked_at"#,
+        person.id,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let timeline = sqlx::query_as!(
+        TimelineItem,
+        r#"
+        SELECT kind as "kind!", at as "at!", title as "title!", detail as "detail!" FROM (
+            SELECT 'contact_submission' AS kind, created_at AS at, 'Contact form (' || source || ')' AS title,
+                   jsonb_build_object('id', id, 'message', message, 'acreage', acreage,
+                                      'crop_type', crop_type, 'status', status) AS detail
+            FROM contact_submissions WHERE person_id = $1
+            UNION ALL
+            SELECT 'waitlist_entry', created_at, 'Joined waitlist (' || interest || ')',
+                   jsonb_build_object('id', id, 'interest', interest)
+            FROM waitlist_entries WHERE person_id = $1
+            UNION ALL
+            SELECT 'lead_status', h.created_at, h.from_status || ' → ' || h.to_status,
+                   jsonb_build_object('contact_id', h.contact_id, 'changed_by', h.changed_by)
+            FROM contact_status_history h JOIN contact_submissions c ON c.id = h.contact_id
+            WHERE c.person_id = $1
+            UNION ALL
+            SELECT 'lead_note', n.created_at, 'Note',
+                   jsonb_build_object('contact_id', n.contact_id, 'author', n.author, 'body', n.body)
+            FROM contact_notes n JOIN contact_submissions c ON c.id = n.contact_id
+            WHERE c.person_id = $1
+            UNION ALL
+            SELECT 'page_view', created_at, path,
+                   jsonb_build_object('visitor_id', visitor_id, 'session_id', session_id, 'referrer', referrer,
+                                      'utm_source', utm_source, 'utm_medium', utm_medium,
+                                      'utm_campaign', utm_campaign)
+            FROM analytics_page_views
+            WHERE visitor_id IN (SELECT visitor_id FROM person_visitors WHERE person_id = $1)
+            UNION ALL
+            SELECT 'event', created_at, event_name,
+                   jsonb_build_object('visitor_id', visitor_id, 'session_id', session_id,
+                                      'category', event_category, 'label', event_label,
+                                      'value', event_value, 'path', path)
+            FROM analytics_events
+            WHERE visitor_id IN (SELECT visitor_id FROM person_visitors WHERE person_id = $1)
+        ) t
+        ORDER BY at DESC
+        LIMIT $2
+        "#,
+        person.id,
+        limit,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(PersonTimeline {
+        person,
+        visitor_ids,
+        timeline,
+    })
+}
+
+// ── Privacy ──────────────────────────────────
+
+/// Record an unsubscribe for `email`, and stop anything already queued for
+/// them that carries an unsubscribe link, i.e. mail they didn't just ask for.
+/// Unknown addresses are a no-op.
+pub async fn unsubscribe(pool: &PgPool, email: &str) -> ApiResult<()> {
+    let email = normalize_email(email);
+    let mut tx = pool.begin().await?;
+    sqlx::query!(
+        r#"UPDATE people SET unsubscribed_at = COALESCE(unsubscribed_at, NOW()) WHERE email = $1"#,
+        email,
+    )
+    .execute(&mut *tx)
+    .await?;
+    sqlx::query!(
+        r#"
+        UPDATE email_outbox
+        SET status = 'dead', last_error = 'Recipient unsubscribed', updated_at = NOW()
+        WHERE LOWER(recipient) = $1 AND status = 'pending' AND unsubscribe_url IS NOT NULL
+        "#,
+        email,
+    )
+    .execute(&mut *tx)
+    .await?;
+    tx.commit().await?;
+    Ok(())
+}
+
+/// Whether `email` has opted out of the mail we send people unprompted.
+pub async fn is_unsubscribed(pool: &PgPool, email: &str) -> ApiResult<bool> {
+    let unsubscribed = sqlx::query_scalar!(
+        r#"SELECT EXISTS (SELECT 1 FROM people WHERE email = $1 AND unsubscribed_at IS NOT NULL) AS "unsubscribed!""#,
+        normalize_email(email),
+    )
+    .fetch_one(pool)
+    .await?;
+    Ok(unsubscribed)
+}
+
+/// Claim the right to email an erasure link to `email`. False when we hold no
+/// data for the address or a link went out in the last 10 minutes.
+pub async fn claim_erasure_request(pool: &PgPool, email: &str) -> ApiResult<bool> {
+    let claimed = sqlx::query_scalar!(
+        r#"
+        UPDATE people SET erasure_requested_at = NOW()
+        WHERE email = $1
+          AND (erasure_requested_at IS NULL OR erasure_requested_at < NOW() - INTERVAL '10 minutes')
+        RETURNING id
+        "#,
+        normalize_email(email),
+    )
+    .fetch_optional(pool)
+    .await?;
+    Ok(claimed.is_some())
+}
+
+/// Remove everything tied to `email` in one transaction: contact submissions
+/// and waitlist entries (their notes and history cascade) and the person
+/// record are deleted, and page views and events from every verified visitor ID
+/// are detached from them and stripped of free-form fields. Aggregate traffic
+/// numbers survive; nothing left points back at the person.
+pub async fn erase_personal_data(
+    pool: &PgPool,
+    email: &str,
+    via: ErasureVia,
+    performed_by: Option<&str>,
+) -> ApiResult<DataErasure> {
+    let email = normalize_email(email);
+    let erasure_id = Uuid::new_v4();
+    let mut tx = pool.begin().await?;
+
+    let visitor_ids = verified_visitor_ids(&mut tx, &email).await?;
+
+    // Rows stay countable as one anonymous visitor, unrelated to any other
+    let anonymous_id = format!("erased:{}", erasure_id.simple());
+    let page_views = sqlx::query!(
+        r#"
+        UPDATE analytics_page_views
+        SET visitor_id = $2, referrer = NULL, user_agent = NULL, utm_term = NULL, utm_content = NULL
+        WHERE visitor_id = ANY($1)
+        "#,
+        &visitor_ids,
+        anonymous_id,
+    )
+    .execute(&mut *tx)
+    .await?
+    .rows_affected();
+    let events = sqlx::query!(
+        r#"
+        UPDATE analytics_events SET visitor_id = $2, properties = '{}'
+        WHERE visitor_id = ANY($1)
+        "#,
+        &visitor_ids,
+        anonymous_id,
+    )
+    .execute(&mut *tx)
+    .await?
+    .rows_affected();
+
+    // Digests mention several people, so they're found through their items.
+    // Items in a digest that hasn't gone out are held for the next one.
+    let digests = sqlx::query_scalar!(
+        r#"
+        SELECT DISTINCT i.outbox_email_id as "id!"
+        FROM notification_digest_items i
+        LEFT JOIN contact_submissions c ON c.id = i.contact_id
+        LEFT JOIN waitlist_entries w ON w.id = i.waitlist_entry_id
+        WHERE i.outbox_email_id IS NOT NULL
+          AND (LOWER(TRIM(c.email)) = $1 OR LOWER(TRIM(w.email)) = $1
+               OR c.person_id IN (SELECT id FROM people WHERE email = $1)
+               OR w.person_id IN (SELECT id FROM people WHERE email = $1))
+        "#,
+        email,
+    )
+    .fetch_all(&mut *tx)
+    .await?;
+    sqlx::query!(
+        r#"
+        UPDATE notification_digest_items SET digested_at = NULL
+        WHERE outbox_email_id IN (SELECT id FROM email_outbox WHERE id = ANY($1) AND status = 'pending')
+        "#,
+        &digests,
+    )
+    .execute(&mut *tx)
+    .await?;
+    sqlx::query!(r#"DELETE FROM email_outbox WHERE id = ANY($1)"#, &digests)
+        .execute(&mut *tx)
+        .await?;
+
+    let contacts = sqlx::query!(
+        r#"
+        DELETE FROM contact_submissions
+        WHERE LOWER(TRIM(email)) = $1
+           OR person_id IN (SELECT id FROM people WHERE email = $1)
+        "#,
+        email,
+    )
+    .execute(&mut *tx)
+    .await?
+    .rows_affected();
+    let waitlist = sqlx::query!(
+        r#"
+        DELETE FROM waitlist_entries
+        WHERE LOWER(TRIM(email)) = $1
+           OR person_id IN (SELECT id FROM people WHERE email = $1)
+        "#,
+        email,
+    )
+    .execute(&mut *tx)
+    .await?
+    .rows_affected();
+    sqlx::query!(r#"DELETE FROM people WHERE email = $1"#, email)
+        .execute(&mut *tx)
+        .await?;
+    // Mail about their submissions cascades with them; this catches the rest.
+    // Digests were withdrawn above, before their items cascaded away.
+    sqlx::query!(
+        r#"DELETE FROM email_outbox WHERE LOWER(recipient) = $1"#,
+        email
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    let erasure = sqlx::query_as!(
+        DataErasure,
+        r#"
+        INSERT INTO data_erasures (
+            id, email_sha256, requested_via, performed_by, contacts_deleted, waitlist_deleted,
+            visitor_ids, page_views_anonymized, events_anonymized
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+        RETURNING *
+        "#,
+        erasure_id,
+        email_sha256(&email),
+        via.as_str(),
+        performed_by,
+        contacts as i64,
+        waitlist as i64,
+        visitor_ids.len() as i64,
+        page_views as i64,
+        events as i64,
+    )
+    .fetch_one(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+    Ok(erasure)
+}
+
+/// Visitor IDs the person has been verified against: only links made by
+/// opening a signed link (see `link_visitor`). A visitor ID that was merely
+/// posted alongside a form could be anyone's browser, so its analytics are not
+/// this person's to read or erase.
+async fn verified_visitor_ids(
+    conn: &mut sqlx::PgConnection,
+    email: &str,
+) -> ApiResult<Vec<String>> {
+    let ids = sqlx::query_scalar!(
+        r#"
+        SELECT pv.visitor_id FROM person_visitors pv
+        JOIN people p ON p.id = pv.person_id WHERE p.email = $1
+        "#,
+        email,
+    )
+    .fetch_all(conn)
+    .await?;
+    Ok(ids)
+}
+
+/// Claim the right to email an access export link to `email`, on the same
+/// terms as `claim_erasure_request`.
+pub async fn claim_access_request(pool: &PgPool, email: &str) -> ApiResult<bool> {
+    let claimed = sqlx::query_scalar!(
+        r#"
+        UPDATE people SET access_requested_at = NOW()
+        WHERE email = $1
+          AND (access_requested_at IS NULL OR access_requested_at < NOW() - INTERVAL '10 minutes')
+        RETURNING id
+        "#,
+        normalize_email(email),
+    )
+    .fetch_optional(pool)
+    .await?;
+    Ok(claimed.is_some())
+}
+
+/// Collect every row about `email`, matched the same way erasure matches it,
+/// read from one snapshot so the sections agree with each other.
+pub async fn subject_access_bundle(pool: &PgPool, email: &str) -> ApiResult<SubjectAccessBundle> {
+    let email = normalize_email(email);
+    let mut tx = pool.begin().await?;
+    sqlx::query!("SET TRANSACTION ISOLATION LEVEL REPEATABLE READ, READ ONLY")
+        .execute(&mut *tx)
+        .await?;
+
+    let person = sqlx::query_as!(Person, r#"SELECT * FROM people WHERE email = $1"#, email)
+        .fetch_optional(&mut *tx)
+        .await?;
+    let contact_submissions = sqlx::query_as!(
+        ContactSubmission,
+        r#"
+        SELECT * FROM contact_submissions
+        WHERE LOWER(TRIM(email)) = $1 OR person_id IN (SELECT id FROM people WHERE email = $1)
+        ORDER BY created_at
+        "#,
+        email,
+    )
+    .fetch_all(&mut *tx)
+    .await?;
+    let contact_ids: Vec<Uuid> = contact_submissions.iter().map(|c| c.id).collect();
+    let contact_notes = sqlx::query_as!(
+        ContactNote,
+        r#"SELECT * FROM contact_notes WHERE contact_id = ANY($1) ORDER BY created_at"#,
+        &contact_ids,
+    )
+    .fetch_all(&mut *tx)
+    .await?;
+    let waitlist_entries = sqlx::query_as!(
+        WaitlistEntry,
+        r#"
+        SELECT * FROM waitlist_entries
+        WHERE LOWER(TRIM(email)) = $1 OR person_id IN (SELECT id FROM people WHERE email = $1)
+        ORDER BY created_at
+        "#,
+        email,
+    )
+    .fetch_all(&mut *tx)
+    .await?;
+
+    let visitor_ids = verified_visitor_ids(&mut tx, &email).await?;
+    let page_views = sqlx::query_as!(
+        PageViewRecord,
+        r#"SELECT * FROM analytics_page_views WHERE visitor_id = ANY($1) ORDER BY created_at"#,
+        &visitor_ids,
+    )
+    .fetch_all(&mut *tx)
+    .await?;
+    let events = sqlx::query_as!(
+        EventRecord,
+        r#"SELECT * FROM analytics_events WHERE visitor_id = ANY($1) ORDER BY created_at"#,
+        &visitor_ids,
+    )
+    .fetch_all(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+    Ok(SubjectAccessBundle {
+        generated_at: Utc::now(),
+        email,
+        person,
+        contact_submissions,
+        contact_notes,
+        waitlist_entries,
+        visitor_ids,
+        page_views,
+        events,
+    })
+}
+
+pub async fn list_erasures(
+    pool: &PgPool,
+    email: Option<&str>,
+    limit: i64,
+) -> ApiResult<Vec<DataErasure>> {
+    let rows = sqlx::query_as!(
+        DataErasure,
+        r#"
+        SELECT * FROM data_erasures
+        WHERE ($1::text IS NULL OR email_sha256 = $1)
+        ORDER BY created_at DESC
+        LIMIT $2
+        "#,
+        email.map(|e| email_sha256(&normalize_email(e))),
+        limit,
+    )
+    .fetch_all(pool)
+    .await?;
+    Ok(rows)
+}
+
+fn email_sha256(normalized_email: &str) -> String {
+    Sha256::digest(normalized_email.as_bytes())
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect()
+}
+
+// ── Email Outbox ─────────────────────────────
+
+pub async fn enqueue_email(
+    db: impl sqlx::PgExecutor<'_>,
+    kind: &str,
+    email: &OutgoingEmail,
+    about: Option<EmailAbout>,
+) -> ApiResult<Uuid> {
+    let (contact_id, waitlist_entry_id) = match about {
+        Some(EmailAbout::Contact(id)) => (Some(id), None),
+        Some(EmailAbout::WaitlistEntry(id)) => (None, Some(id)),
+        None => (None, None),
+    };
+    let id = sqlx::query_scalar!(
+        r#"
+        INSERT INTO email_outbox (
+            kind, recipient, subject, text_body, html_body, unsubscribe_url, contact_id, waitlist_entry_id
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+        RETURNING id
+        "#,
+        kind,
+        email.to,
+        email.subject,
+        email.text,
+        email.html,
+        email.unsubscribe_url,
+        contact_id,
+        waitlist_entry_id,
+    )
+    .fetch_one(db)
+    .await?;
+    Ok(id)
+}
+
+/// Claim up to `limit` due messages for delivery. Each claim counts as an
+/// attempt and hides the message for `lease_secs`, so concurrent workers skip
+/// it and a worker that dies mid-send only delays it.
+pub async fn claim_due_emails(
+    pool: &PgPool,
+    limit: i64,
+    lease_secs: f64,
+) -> ApiResult<Vec<QueuedEmail>> {
+    let emails = sqlx::query_as!(
+        QueuedEmail,
+        r#"
+        UPDATE email_outbox
+        SET attempts = attempts + 1,
+            next_attempt_at = NOW() + make_interval(secs => $2),
+            updated_at = NOW()
+        WHERE id IN (
+            SELECT id FROM email_outbox
+            WHERE status = 'pending' AND next_attempt_at <= NOW()
+            ORDER BY next_attempt_at
+            LIMIT $1
+            FOR UPDATE SKIP LOCKED
+        )
+        RETURNING id, recipient, subject, text_body, html_body, unsubscribe_url, attempts
+        "#,
+        limit,
+        lease_secs,
+    )
+    .fetch_all(pool)
+    .await?;
+    Ok(emails)
+}
+
+pub async fn mark_email_sent(pool: &PgPool, id: Uuid) -> ApiResult<()> {
+    sqlx::query!(
+        r#"
+        UPDATE email_outbox
+        SET status = 'sent', sent_at = NOW(), last_error = NULL, updated_at = NOW()
+        WHERE id = $1
+        "#,
+        id,
+    )
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+/// Record a failed send: try again at `retry_at`, or with `None` give up and
+/// mark the message dead.
+pub async fn mark_email_failed(
+    pool: &PgPool,
+    id: Uuid,
+    error: &str,
+    retry_at: Option<DateTime<Utc>>,
+) -> ApiResult<()> {
+    sqlx::query!(
+        r#"
+        UPDATE email_outbox
+        SET status = CASE WHEN $3::timestamptz IS NULL THEN 'dead' ELSE 'pending' END,
+            next_attempt_at = COALESCE($3, next_attempt_at),
+            last_error = $2,
+            updated_at = NOW()
+        WHERE id = $1
+        "#,
+        id,
+        error,
+        retry_at,
+    )
+    .execute(pool)
+    .await?;
+    Ok(())
+}
 
-pub async fn list_blog_posts(pool: &PgPool, limit: i64, offset: i64) -> ApiResult<Vec<BlogPostSummary>> {
-    let posts = sqlx::query_as!(
-        BlogPostSummary,
+pub async fn list_outbox(
+    pool: &PgPool,
+    status: Option<&str>,
+    limit: i64,
+) -> ApiResult<Vec<OutboxEmail>> {
+    let emails = sqlx::query_as!(
+        OutboxEmail,
         r#"
-        SELECT id, slug, title, subtitle, category, hero_image_url,
-               excerpt, author_name, read_time_minutes, published_at
-        FROM blog_posts
-        WHERE published = true
-        ORDER BY published_at DESC
-        LIMIT $1 OFFSET $2
+        SELECT id, kind, recipient, subject, contact_id, waitlist_entry_id, status, attempts,
+               next_attempt_at, last_error, sent_at, created_at, updated_at
+        FROM email_outbox
+        WHERE ($1::text IS NULL OR status = $1)
+        ORDER BY created_at DESC
+        LIMIT $2
         "#,
+        status,
         limit,
-        offset
     )
     .fetch_all(pool)
     .await?;
+    Ok(emails)
+}
 
-    Ok(posts)
+async fn emails_about(pool: &PgPool, about: EmailAbout) -> ApiResult<Vec<OutboxEmail>> {
+    let (contact_id, waitlist_entry_id) = match about {
+        EmailAbout::Contact(id) => (Some(id), None),
+        EmailAbout::WaitlistEntry(id) => (None, Some(id)),
+    };
+    let emails = sqlx::query_as!(
+        OutboxEmail,
+        r#"
+        SELECT id, kind, recipient, subject, contact_id, waitlist_entry_id, status, attempts,
+               next_attempt_at, last_error, sent_at, created_at, updated_at
+        FROM email_outbox
+        WHERE contact_id = $1 OR waitlist_entry_id = $2
+        ORDER BY created_at DESC
+        "#,
+        contact_id,
+        waitlist_entry_id,
+    )
+    .fetch_all(pool)
+    .await?;
+    Ok(emails)
 }
 
-pub async fn get_blog_post_by_slug(pool: &PgPool, slug: &str) -> ApiResult<BlogPost> {
-    sqlx::query_as!(
-        BlogPost,
-        r#"SELECT * FROM blog_posts WHERE slug = $1 AND published = true"#,
-        slug
+pub async fn outbox_stats(pool: &PgPool) -> ApiResult<OutboxStats> {
+    let stats = sqlx::query_as!(
+        OutboxStats,
+        r#"
+        SELECT
+            COUNT(*) FILTER (WHERE status = 'pending') AS "pending!",
+            COUNT(*) FILTER (WHERE status = 'pending' AND attempts > 0) AS "retrying!",
+            COUNT(*) FILTER (WHERE status = 'sent') AS "sent!",
+            COUNT(*) FILTER (WHERE status = 'dead') AS "dead!",
+            MIN(created_at) FILTER (WHERE status = 'pending') AS oldest_pending_at
+        FROM email_outbox
+        "#,
+    )
+    .fetch_one(pool)
+    .await?;
+    Ok(stats)
+}
+
+/// Put a dead (or still pending) message back in line for immediate delivery
+/// with a fresh set of attempts.
+pub async fn retry_email(pool: &PgPool, id: Uuid) -> ApiResult<OutboxEmail> {
+    let email = sqlx::query_as!(
+        OutboxEmail,
+        r#"
+        UPDATE email_outbox
+        SET status = 'pending', attempts = 0, next_attempt_at = NOW(), updated_at = NOW()
+        WHERE id = $1 AND status <> 'sent'
+        RETURNING id, kind, recipient, subject, contact_id, waitlist_entry_id, status, attempts,
+                  next_attempt_at, last_error, sent_at, created_at, updated_at
+        "#,
+        id,
     )
     .fetch_optional(pool)
-    .await?
-    .ok_or_else(|| ApiError::NotFound(format!("Post '{slug}' not found")))
+    .await?;
+    match email {
+        Some(email) => Ok(email),
+        None => {
+            let exists = sqlx::query_scalar!(
+                r#"SELECT EXISTS(SELECT 1 FROM email_outbox WHERE id = $1) AS "exists!""#,
+                id
+            )
+            .fetch_one(pool)
+            .await?;
+            Err(if exists {
+                ApiError::Conflict("This email has already been sent".to_string())
+            } else {
+                ApiError::NotFound(format!("Email {id} not found"))
+            })
+        }
+    }
 }
 
-pub async fn create_contact(pool: &PgPool, req: &ContactRequest) -> ApiResult<ContactSubmission> {
-    let submission = sqlx::query_as!(
-        ContactSubmission,
+/// Whether to acknowledge a contact from `email`: not if they unsubscribed,
+/// or were sent a `kind` email in the last `window_hours`.
+pub async fn contact_auto_reply_allowed(
+    db: impl sqlx::PgExecutor<'_>,
+    email: &str,
+    kind: &str,
+    window_hours: i32,
+) -> ApiResult<bool> {
+    let email = normalize_email(email);
+    let allowed = sqlx::query_scalar!(
         r#"
-        INSERT INTO contact_submissions (id, name, email, company, phone, acreage, crop_type, message, source)
-        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
-        RETURNING *
+        SELECT NOT EXISTS (SELECT 1 FROM people WHERE email = $1 AND unsubscribed_at IS NOT NULL)
+           AND NOT EXISTS (
+               SELECT 1 FROM email_outbox
+               WHERE LOWER(recipient) = $1 AND kind = $2
+                 AND created_at > NOW() - make_interval(hours => $3)
+           ) AS "allowed!"
         "#,
-        Uuid::new_v4(),
-        req.name,
-        req.email,
-        req.company,
-        req.phone,
-        req.acreage,
-        req.crop_type,
-        req.message,
-        req.source.clone().unwrap_or_else(|| "website".to_string()),
+        email,
+        kind,
+        window_hours,
+    )
+    .fetch_one(db)
+    .await?;
+    Ok(allowed)
+}
+
+pub async fn get_auto_reply_config(
+    db: impl sqlx::PgExecutor<'_>,
+) -> ApiResult<AutoReplyConfigResponse> {
+    let row = sqlx::query!(r#"SELECT config, updated_by, updated_at FROM auto_reply_config"#)
+        .fetch_optional(db)
+        .await?;
+    let Some(row) = row else {
+        return Ok(AutoReplyConfigResponse {
+            config: AutoReplyConfig::default(),
+            updated_by: None,
+            updated_at: None,
+        });
+    };
+    let config = serde_json::from_value(row.config).map_err(|e| {
+        ApiError::Internal(anyhow::anyhow!("Invalid stored auto-reply config: {e}"))
+    })?;
+    Ok(AutoReplyConfigResponse {
+        config,
+        updated_by: row.updated_by,
+        updated_at: Some(row.updated_at),
+    })
+}
+
+pub async fn save_auto_reply_config(
+    pool: &PgPool,
+    req: &AutoReplyConfigRequest,
+) -> ApiResult<AutoReplyConfigResponse> {
+    let config = serde_json::to_value(&req.config).map_err(|e| ApiError::Internal(e.into()))?;
+    let row = sqlx::query!(
+        r#"
+        INSERT INTO auto_reply_config (id, config, updated_by)
+        VALUES (TRUE, $1, $2)
+        ON CONFLICT (id) DO UPDATE SET
+            config = EXCLUDED.config,
+            updated_by = EXCLUDED.updated_by,
+            updated_at = NOW()
+        RETURNING updated_by, updated_at
+        "#,
+        config,
+        req.updated_by,
     )
     .fetch_one(pool)
     .await?;
+    Ok(AutoReplyConfigResponse {
+        config: req.config.clone(),
+        updated_by: row.updated_by,
+        updated_at: Some(row.updated_at),
+    })
+}
 
-    Ok(submission)
+pub async fn get_routing_config(db: impl sqlx::PgExecutor<'_>) -> ApiResult<RoutingConfigResponse> {
+    let row =
+        sqlx::query!(r#"SELECT config, updated_by, updated_at FROM notification_routing_config"#)
+            .fetch_optional(db)
+            .await?;
+    let Some(row) = row else {
+        return Ok(RoutingConfigResponse {
+            config: RoutingConfig::default(),
+            updated_by: None,
+            updated_at: None,
+        });
+    };
+    let config = serde_json::from_value(row.config).map_err(|e| {
+        ApiError::Internal(anyhow::anyhow!(
+            "Invalid stored notification routing config: {e}"
+        ))
+    })?;
+    Ok(RoutingConfigResponse {
+        config,
+        updated_by: row.updated_by,
+        updated_at: Some(row.updated_at),
+    })
 }
 
-pub async fn create_waitlist_entry(pool: &PgPool, req: &WaitlistRequest) -> ApiResult<WaitlistEntry> {
-    let entry = sqlx::query_as!(
-        WaitlistEntry,
+pub async fn save_routing_config(
+    pool: &PgPool,
+    req: &RoutingConfigRequest,
+) -> ApiResult<RoutingConfigResponse> {
+    let config = serde_json::to_value(&req.config).map_err(|e| ApiError::Internal(e.into()))?;
+    let row = sqlx::query!(
         r#"
-        INSERT INTO waitlist_entries (id, email, name, company, interest)
-        VALUES ($1, $2, $3, $4, $5)
-        ON CONFLICT (email, interest) DO UPDATE SET name = EXCLUDED.name, company = EXCLUDED.company
-        RETURNING *
+        INSERT INTO notification_routing_config (id, config, updated_by)
+        VALUES (TRUE, $1, $2)
+        ON CONFLICT (id) DO UPDATE SET
+            config = EXCLUDED.config,
+            updated_by = EXCLUDED.updated_by,
+            updated_at = NOW()
+        RETURNING updated_by, updated_at
         "#,
-        Uuid::new_v4(),
-        req.email,
-        req.name,
-        req.company,
-        req.interest.clone().unwrap_or_else(|| "general".to_string()),
+        config,
+        req.updated_by,
     )
     .fetch_one(pool)
     .await?;
+    Ok(RoutingConfigResponse {
+        config: req.config.clone(),
+        updated_by: row.updated_by,
+        updated_at: Some(row.updated_at),
+    })
+}
 
-    Ok(entry)
+/// Hold a submission for `recipient`'s next daily digest.
+pub async fn add_digest_item(
+    db: impl sqlx::PgExecutor<'_>,
+    recipient: &str,
+    route: &str,
+    about: EmailAbout,
+) -> ApiResult<()> {
+    let (contact_id, waitlist_entry_id) = match about {
+        EmailAbout::Contact(id) => (Some(id), None),
+        EmailAbout::WaitlistEntry(id) => (None, Some(id)),
+    };
+    sqlx::query!(
+        r#"
+        INSERT INTO notification_digest_items (recipient, route, contact_id, waitlist_entry_id)
+        VALUES ($1, $2, $3, $4)
+        "#,
+        recipient,
+        route,
+        contact_id,
+        waitlist_entry_id,
+    )
+    .execute(db)
+    .await?;
+    Ok(())
+}
+
+/// Mark every item held since before `cutoff` as digested and return them
+/// with their submissions. Runs in the caller's transaction so the items are
+/// only used up if the digests are queued too.
+pub async fn claim_digest_batch(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    cutoff: DateTime<Utc>,
+) -> ApiResult<DigestBatch> {
+    let items = sqlx::query_as!(
+        DigestItem,
+        r#"
+        UPDATE notification_digest_items SET digested_at = NOW()
+        WHERE digested_at IS NULL AND created_at < $1
+        RETURNING id, recipient, route, contact_id, waitlist_entry_id
+        "#,
+        cutoff,
+    )
+    .fetch_all(&mut **tx)
+    .await?;
+    let contact_ids: Vec<Uuid> = items.iter().filter_map(|item| item.contact_id).collect();
+    let entry_ids: Vec<Uuid> = items
+        .iter()
+        .filter_map(|item| item.waitlist_entry_id)
+        .collect();
+    let contacts = sqlx::query_as!(
+        ContactSubmission,
+        r#"SELECT * FROM contact_submissions WHERE id = ANY($1) ORDER BY created_at"#,
+        &contact_ids,
+    )
+    .fetch_all(&mut **tx)
+    .await?;
+    let waitlist_entries = sqlx::query_as!(
+        WaitlistEntry,
+        r#"SELECT * FROM waitlist_entries WHERE id = ANY($1) ORDER BY created_at"#,
+        &entry_ids,
+    )
+    .fetch_all(&mut **tx)
+    .await?;
+    Ok(DigestBatch {
+        items,
+        contacts,
+        waitlist_entries,
+    })
+}
+
+/// Record the digest email that carried `item_ids`, so an erasure can find
+/// every digest that mentions someone.
+pub async fn link_digest_items(
+    db: impl sqlx::PgExecutor<'_>,
+    email_id: Uuid,
+    item_ids: &[Uuid],
+) -> ApiResult<()> {
+    sqlx::query!(
+        r#"UPDATE notification_digest_items SET outbox_email_id = $1 WHERE id = ANY($2)"#,
+        email_id,
+        item_ids,
+    )
+    .execute(db)
+    .await?;
+    Ok(())
+}
+
+/// Drop delivered messages, and dead ones nobody retried, once they're older
+/// than `days`; they hold personal data and have no further use.
+pub async fn purge_finished_emails(pool: &PgPool, days: i32) -> ApiResult<u64> {
+    let purged = sqlx::query!(
+        r#"
+        DELETE FROM email_outbox
+        WHERE (status = 'sent' AND sent_at < NOW() - make_interval(days => $1))
+           OR (status = 'dead' AND updated_at < NOW() - make_interval(days => $1))
+        "#,
+        days,
+    )
+    .execute(pool)
+    .await?
+    .rows_affected();
+    Ok(purged)
 }
 
 // ── Admin ────────────────────────────────────
@@ -88,9 +2195,10 @@ pub async fn admin_stats(pool: &PgPool) -> ApiResult<AdminStats> {
         r#"
         SELECT
             (SELECT COUNT(*) FROM contact_submissions) as "total_contacts!",
-            (SELECT COUNT(*) FROM waitlist_entries) as "total_waitlist!",
+            (SELECT COUNT(*) FROM waitlist_entries WHERE confirmed_at IS NOT NULL) as "total_waitlist!",
             (SELECT COUNT(*) FROM contact_submissions WHERE created_at >= CURRENT_DATE) as "contacts_today!",
-            (SELECT COUNT(*) FROM waitlist_entries WHERE created_at >= CURRENT_DATE) as "waitlist_today!"
+            (SELECT COUNT(*) FROM waitlist_entries
+             WHERE confirmed_at IS NOT NULL AND created_at >= CURRENT_DATE) as "waitlist_today!"
         "#,
     )
     .fetch_one(pool)
@@ -98,30 +2206,626 @@ pub async fn admin_stats(pool: &PgPool) -> ApiResult<AdminStats> {
     Ok(stats)
 }
 
-pub async fn list_contacts(pool: &PgPool, limit: i64, offset: i64) -> ApiResult<Vec<ContactSubmission>> {
-    let rows = sqlx::query_as!(
+/// One page of contacts, newest first (or highest score first), starting
+/// after `after`.
+pub async fn list_contacts(
+    pool: &PgPool,
+    filter: &ContactFilter,
+    by_score: bool,
+    after: Option<SubmissionCursor>,
+    limit: i64,
+) -> ApiResult<Page<ContactSubmission>> {
+    let mut rows = sqlx::query_as!(
         ContactSubmission,
-        r#"SELECT * FROM contact_submissions ORDER BY created_at DESC LIMIT $1 OFFSET $2"#,
-        limit,
-        offset,
+        r#"
+        SELECT * FROM contact_submissions
+        WHERE ($1::text IS NULL OR name ILIKE $1 OR email ILIKE $1 OR company ILIKE $1)
+          AND ($2::text IS NULL OR status = $2)
+          AND ($3::text IS NULL OR owner = $3)
+          AND ($4::text IS NULL OR source = $4)
+          AND ($5::text IS NULL OR crop_type_normalized = $5)
+          AND ($6::timestamptz IS NULL OR created_at >= $6)
+          AND ($7::timestamptz IS NULL OR created_at < $7)
+          AND ($9::timestamptz IS NULL OR CASE
+                WHEN $8 THEN (COALESCE(score, -2147483648), created_at, id) < ($10::int, $9, $11::uuid)
+                ELSE (created_at, id) < ($9, $11::uuid)
+              END)
+        ORDER BY CASE WHEN $8 THEN COALESCE(score, -2147483648) END DESC, created_at DESC, id DESC
+        LIMIT $12
+        "#,
+        filter.search.as_deref().map(like_pattern),
+        filter.status,
+        filter.owner,
+        filter.source,
+        filter.crop_type,
+        filter.from,
+        filter.to,
+        by_score,
+        after.map(|c| c.created_at),
+        after.and_then(|c| c.score),
+        after.map(|c| c.id),
+        limit + 1,
     )
     .fetch_all(pool)
     .await?;
-    Ok(rows)
+
+    let next_cursor = next_page(&mut rows, limit, |c| SubmissionCursor {
+        score: by_score.then(|| c.score.unwrap_or(i32::MIN)),
+        created_at: c.created_at,
+        id: c.id,
+    });
+    Ok(Page {
+        items: rows,
+        next_cursor,
+    })
+}
+
+// ── Lead Pipeline ────────────────────────────
+
+pub async fn get_contact(pool: &PgPool, id: Uuid) -> ApiResult<ContactSubmission> {
+    sqlx::query_as!(
+        ContactSubmission,
+        r#"SELECT * FROM contact_submissions WHERE id = $1"#,
+        id
+    )
+    .fetch_optional(pool)
+    .await?
+    .ok_or_else(|| ApiError::NotFound(format!("Contact {id} not found")))
+}
+
+pub async fn contact_detail(pool: &PgPool, contact: ContactSubmission) -> ApiResult<ContactDetail> {
+    let notes = sqlx::query_as!(
+        ContactNote,
+        r#"SELECT * FROM contact_notes WHERE contact_id = $1 ORDER BY created_at DESC"#,
+        contact.id,
+    )
+    .fetch_all(pool)
+    .await?;
+    let status_history = sqlx::query_as!(
+        ContactStatusChange,
+        r#"SELECT * FROM contact_status_history WHERE contact_id = $1 ORDER BY created_at DESC"#,
+        contact.id,
+    )
+    .fetch_all(pool)
+    .await?;
+    let emails = emails_about(pool, EmailAbout::Contact(contact.id)).await?;
+    Ok(ContactDetail {
+        contact,
+        notes,
+        status_history,
+        emails,
+    })
+}
+
+/// Move a lead to `req.status`, recording the change (and optional note) in the
+/// same transaction. The row lock keeps concurrent moves from skipping history.
+pub async fn set_contact_status(
+    pool: &PgPool,
+    id: Uuid,
+    req: &LeadStatusRequest,
+) -> ApiResult<ContactSubmission> {
+    let mut tx = pool.begin().await?;
+    let current = sqlx::query_scalar!(
+        r#"SELECT status FROM contact_submissions WHERE id = $1 FOR UPDATE"#,
+        id
+    )
+    .fetch_optional(&mut *tx)
+    .await?
+    .ok_or_else(|| ApiError::NotFound(format!("Contact {id} not found")))?;
+
+    let from = LeadStatus::parse(&current)
+        .ok_or_else(|| ApiError::Internal(anyhow::anyhow!("Unknown lead status '{current}'")))?;
+    if !from.can_transition_to(req.status) {
+        return Err(ApiError::Validation(format!(
+            "Cannot move lead from {} to {}",
+            from.as_str(),
+            req.status.as_str()
+        )));
+    }
+
+    let contact = sqlx::query_as!(
+        ContactSubmission,
+        r#"
+        UPDATE contact_submissions SET status = $2, status_changed_at = NOW()
+        WHERE id = $1
+        RETURNING *
+        "#,
+        id,
+        req.status.as_str(),
+    )
+    .fetch_one(&mut *tx)
+    .await?;
+
+    sqlx::query!(
+        r#"
+        INSERT INTO contact_status_history (contact_id, from_status, to_status, changed_by)
+        VALUES ($1, $2, $3, $4)
+        "#,
+        id,
+        from.as_str(),
+        req.status.as_str(),
+        req.changed_by,
+    )
+    .execute(&mut *tx)
+    .await?;
+
+    if let Some(note) = &req.note {
+        sqlx::query!(
+            r#"INSERT INTO contact_notes (contact_id, author, body) VALUES ($1, $2, $3)"#,
+            id,
+            req.changed_by,
+            note,
+        )
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    tx.commit().await?;
+    Ok(contact)
+}
+
+pub async fn set_contact_owner(
+    pool: &PgPool,
+    id: Uuid,
+    owner: Option<&str>,
+) -> ApiResult<ContactSubmission> {
+    sqlx::query_as!(
+        ContactSubmission,
+        r#"UPDATE contact_submissions SET owner = $2 WHERE id = $1 RETURNING *"#,
+        id,
+        owner,
+    )
+    .fetch_optional(pool)
+    .await?
+    .ok_or_else(|| ApiError::NotFound(format!("Contact {id} not found")))
+}
+
+pub async fn add_contact_note(
+    pool: &PgPool,
+    id: Uuid,
+    req: &ContactNoteRequest,
+) -> ApiResult<ContactNote> {
+    sqlx::query_as!(
+        ContactNote,
+        r#"
+        INSERT INTO contact_notes (contact_id, author, body)
+        SELECT id, $2, $3 FROM contact_submissions WHERE id = $1
+        RETURNING *
+        "#,
+        id,
+        req.author,
+        req.body,
+    )
+    .fetch_optional(pool)
+    .await?
+    .ok_or_else(|| ApiError::NotFound(format!("Contact {id} not found")))
 }
 
-pub async fn list_waitlist(pool: &PgPool, limit: i64, offset: i64) -> ApiResult<Vec<WaitlistEntry>> {
+/// Lead count per pipeline stage, in lifecycle order.
+pub async fn lead_pipeline(pool: &PgPool) -> ApiResult<Vec<PipelineStage>> {
     let rows = sqlx::query_as!(
-        WaitlistEntry,
-        r#"SELECT * FROM waitlist_entries ORDER BY created_at DESC LIMIT $1 OFFSET $2"#,
-        limit,
-        offset,
+        PipelineStage,
+        r#"
+        SELECT s.status as "status!", COUNT(c.id) as "count!"
+        FROM UNNEST(ARRAY['new', 'contacted', 'qualified', 'pilot', 'closed_lost'])
+             WITH ORDINALITY AS s(status, ord)
+        LEFT JOIN contact_submissions c ON c.status = s.status
+        GROUP BY s.status, s.ord
+        ORDER BY s.ord
+        "#,
     )
     .fetch_all(pool)
     .await?;
     Ok(rows)
 }
 
+pub async fn list_waitlist(
+    pool: &PgPool,
+    filter: &WaitlistFilter,
+    after: Option<SubmissionCursor>,
+    limit: i64,
+) -> ApiResult<Page<WaitlistEntry>> {
+    let mut rows = sqlx::query_as!(
+        WaitlistEntry,
+        r#"
+        SELECT * FROM waitlist_entries
+        WHERE ($1::text IS NULL OR name ILIKE $1 OR email ILIKE $1 OR company ILIKE $1)
+          AND ($2::text IS NULL OR interest = $2)
+          AND ($3::timestamptz IS NULL OR created_at >= $3)
+          AND ($4::timestamptz IS NULL OR created_at < $4)
+          AND ($8::bool IS NULL OR (confirmed_at IS NOT NULL) = $8)
+          AND ($9::text IS NULL OR pilot_status = $9)
+          AND ($10::uuid IS NULL OR cohort_id = $10)
+          AND ($5::timestamptz IS NULL OR (created_at, id) < ($5, $6::uuid))
+        ORDER BY created_at DESC, id DESC
+        LIMIT $7
+        "#,
+        filter.search.as_deref().map(like_pattern),
+        filter.interest,
+        filter.from,
+        filter.to,
+        after.map(|c| c.created_at),
+        after.map(|c| c.id),
+        limit + 1,
+        filter.confirmed,
+        filter.pilot_status,
+        filter.cohort_id,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let next_cursor = next_page(&mut rows, limit, |e| SubmissionCursor {
+        score: None,
+        created_at: e.created_at,
+        id: e.id,
+    });
+    Ok(Page {
+        items: rows,
+        next_cursor,
+    })
+}
+
+/// Trim the extra look-ahead row fetched past `limit`; if there was one, the
+/// cursor points after the last row kept.
+fn next_page<T>(
+    rows: &mut Vec<T>,
+    limit: i64,
+    cursor: impl Fn(&T) -> SubmissionCursor,
+) -> Option<String> {
+    if rows.len() as i64 <= limit {
+        return None;
+    }
+    rows.truncate(limit as usize);
+    rows.last().map(|row| cursor(row).encode())
+}
+
+/// Substring ILIKE pattern with the user's `%`, `_` and `\` taken literally.
+fn like_pattern(search: &str) -> String {
+    let escaped = search
+        .trim()
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_");
+    format!("%{escaped}%")
+}
+
+// ── Exports ──────────────────────────────────
+
+/// Every contact matching the filters, oldest first, read from a cursor rather
+/// than buffered. Owns its pool handle so it can back a streaming response.
+pub fn export_contacts(
+    pool: PgPool,
+    from: Option<DateTime<Utc>>,
+    to: Option<DateTime<Utc>>,
+    source: Option<String>,
+) -> impl Stream<Item = Result<ContactSubmission, sqlx::Error>> + Send + 'static {
+    try_stream! {
+        let mut rows = sqlx::query_as!(
+            ContactSubmission,
+            r#"
+            SELECT * FROM contact_submissions
+            WHERE ($1::timestamptz IS NULL OR created_at >= $1)
+              AND ($2::timestamptz IS NULL OR created_at < $2)
+              AND ($3::text IS NULL OR source = $3)
+            ORDER BY created_at, id
+            "#,
+            from,
+            to,
+            source,
+        )
+        .fetch(&pool);
+        while let Some(row) = rows.try_next().await? {
+            yield row;
+        }
+    }
+}
+
+/// Confirmed entries for the mailing tool, leaving out anyone who unsubscribed.
+pub fn export_waitlist(
+    pool: PgPool,
+    from: Option<DateTime<Utc>>,
+    to: Option<DateTime<Utc>>,
+    interest: Option<String>,
+) -> impl Stream<Item = Result<WaitlistEntry, sqlx::Error>> + Send + 'static {
+    try_stream! {
+        let mut rows = sqlx::query_as!(
+            WaitlistEntry,
+            r#"
+            SELECT w.* FROM waitlist_entries w
+            WHERE w.confirmed_at IS NOT NULL
+              AND NOT EXISTS (
+                  SELECT 1 FROM people p WHERE p.id = w.person_id AND p.unsubscribed_at IS NOT NULL
+              )
+              AND ($1::timestamptz IS NULL OR created_at >= $1)
+              AND ($2::timestamptz IS NULL OR created_at < $2)
+              AND ($3::text IS NULL OR interest = $3)
+            ORDER BY created_at, id
+            "#,
+            from,
+            to,
+            interest,
+        )
+        .fetch(&pool);
+        while let Some(row) = rows.try_next().await? {
+            yield row;
+        }
+    }
+}
+
+// ── Contact Normalization ────────────────────
+
+const CONTACT_NORMALIZATION_BACKFILL: &str = "contact_normalization";
+
+/// Fill in parsed acreage and crop for contacts saved before the parser
+/// existed; new contacts are parsed on insert. Runs once per database, guarded
+/// by a `data_backfills` row. Returns how many rows changed, or `None` if it
+/// has already run.
+pub async fn backfill_contact_normalization(pool: &PgPool) -> ApiResult<Option<u64>> {
+    let mut tx = pool.begin().await?;
+    let claimed = sqlx::query!(
+        r#"INSERT INTO data_backfills (name) VALUES ($1) ON CONFLICT (name) DO NOTHING"#,
+        CONTACT_NORMALIZATION_BACKFILL,
+    )
+    .execute(&mut *tx)
+    .await?
+    .rows_affected();
+    if claimed == 0 {
+        return Ok(None);
+    }
+
+    let rows = sqlx::query!(
+        r#"
+        SELECT id, acreage, crop_type FROM contact_submissions
+        WHERE (acreage IS NOT NULL AND acreage_unit IS NULL)
+           OR (crop_type IS NOT NULL AND crop_type_normalized IS NULL)
+        "#
+    )
+    .fetch_all(&mut *tx)
+    .await?;
+    let mut ids = Vec::with_capacity(rows.len());
+    let mut values = Vec::with_capacity(rows.len());
+    let mut units = Vec::with_capacity(rows.len());
+    let mut crops = Vec::with_capacity(rows.len());
+    for row in rows {
+        let acreage = row.acreage.as_deref().and_then(normalize::parse_acreage);
+        ids.push(row.id);
+        values.push(acreage.map(|a| a.value));
+        units.push(acreage.map(|a| a.unit.as_str().to_string()));
+        crops.push(
+            row.crop_type
+                .as_deref()
+                .and_then(normalize::normalize_crop)
+                .map(str::to_string),
+        );
+    }
+
+    // Only rows whose values actually change count
+    let updated = sqlx::query!(
+        r#"
+        UPDATE contact_submissions c
+        SET acreage_value = COALESCE(u.acreage_value, c.acreage_value),
+            acreage_unit = COALESCE(u.acreage_unit, c.acreage_unit),
+            crop_type_normalized = COALESCE(u.crop, c.crop_type_normalized)
+        FROM UNNEST($1::uuid[], $2::float8[], $3::text[], $4::text[]) AS u(id, acreage_value, acreage_unit, crop)
+        WHERE c.id = u.id
+          AND (COALESCE(u.acreage_value, c.acreage_value), COALESCE(u.acreage_unit, c.acreage_unit),
+               COALESCE(u.crop, c.crop_type_normalized))
+              IS DISTINCT FROM (c.acreage_value, c.acreage_unit, c.crop_type_normalized)
+        "#,
+        &ids,
+        &values as &[Option<f64>],
+        &units as &[Option<String>],
+        &crops as &[Option<String>],
+    )
+    .execute(&mut *tx)
+    .await?
+    .rows_affected();
+
+    sqlx::query!(
+        r#"UPDATE data_backfills SET rows_updated = $2, completed_at = NOW() WHERE name = $1"#,
+        CONTACT_NORMALIZATION_BACKFILL,
+        updated as i64,
+    )
+    .execute(&mut *tx)
+    .await?;
+    tx.commit().await?;
+    Ok(Some(updated))
+}
+
+// ── Lead Scoring ─────────────────────────────
+
+pub async fn get_scoring_config(db: impl sqlx::PgExecutor<'_>) -> ApiResult<ScoringConfigResponse> {
+    let row = sqlx::query!(r#"SELECT config, updated_by, updated_at FROM lead_scoring_config"#)
+        .fetch_optional(db)
+        .await?;
+    let Some(row) = row else {
+        return Ok(ScoringConfigResponse {
+            config: ScoringConfig::default(),
+            updated_by: None,
+            updated_at: None,
+        });
+    };
+    let config: ScoringConfig = serde_json::from_value(row.config)
+        .map_err(|e| ApiError::Internal(anyhow::anyhow!("Invalid stored scoring config: {e}")))?;
+    config
+        .check_crop_ids()
+        .map_err(|e| ApiError::Internal(anyhow::anyhow!("Invalid stored scoring config: {e}")))?;
+    Ok(ScoringConfigResponse {
+        config,
+        updated_by: row.updated_by,
+        updated_at: Some(row.updated_at),
+    })
+}
+
+pub async fn save_scoring_config(
+    pool: &PgPool,
+    req: &ScoringConfigRequest,
+) -> ApiResult<ScoringConfigResponse> {
+    let config = serde_json::to_value(&req.config).map_err(|e| ApiError::Internal(e.into()))?;
+    let row = sqlx::query!(
+        r#"
+        INSERT INTO lead_scoring_config (id, config, updated_by)
+        VALUES (TRUE, $1, $2)
+        ON CONFLICT (id) DO UPDATE SET
+            config = EXCLUDED.config,
+            updated_by = EXCLUDED.updated_by,
+            updated_at = NOW()
+        RETURNING updated_by, updated_at
+        "#,
+        config,
+        req.updated_by,
+    )
+    .fetch_one(pool)
+    .await?;
+    Ok(ScoringConfigResponse {
+        config: req.config.clone(),
+        updated_by: row.updated_by,
+        updated_at: Some(row.updated_at),
+    })
+}
+
+/// Page views and events from every visitor ID linked to the contact's person,
+/// plus the one sent with the submission itself.
+async fn contact_engagement(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    contact: &ContactSubmission,
+) -> ApiResult<Engagement> {
+    let page_views = sqlx::query_scalar!(
+        r#"
+        SELECT COUNT(*) as "count!" FROM analytics_page_views
+        WHERE visitor_id IN (SELECT visitor_id FROM person_visitors WHERE person_id = $1)
+           OR visitor_id = $2
+        "#,
+        contact.person_id,
+        contact.visitor_id,
+    )
+    .fetch_one(&mut **tx)
+    .await?;
+
+    let events = sqlx::query!(
+        r#"
+        SELECT event_name, COUNT(*) as "count!" FROM analytics_events
+        WHERE visitor_id IN (SELECT visitor_id FROM person_visitors WHERE person_id = $1)
+           OR visitor_id = $2
+        GROUP BY event_name
+        "#,
+        contact.person_id,
+        contact.visitor_id,
+    )
+    .fetch_all(&mut **tx)
+    .await?
+    .into_iter()
+    .map(|row| (row.event_name, row.count))
+    .collect();
+
+    Ok(Engagement { page_views, events })
+}
+
+pub async fn score_contact(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    config: &ScoringConfig,
+    contact: &ContactSubmission,
+) -> ApiResult<ContactSubmission> {
+    let engagement = contact_engagement(tx, contact).await?;
+    let LeadScore { score, explanation } = scoring::score_contact(config, contact, &engagement);
+    let scored = sqlx::query_as!(
+        ContactSubmission,
+        r#"
+        UPDATE contact_submissions
+        SET score = $2, score_explanation = $3, scored_at = NOW()
+        WHERE id = $1
+        RETURNING *
+        "#,
+        contact.id,
+        score,
+        explanation,
+    )
+    .fetch_one(&mut **tx)
+    .await?;
+    Ok(scored)
+}
+
+/// Recompute every contact's score with the current model. Engagement for all
+/// contacts is counted in two grouped queries and the scores written in one
+/// UPDATE, so the cost doesn't grow by round trips per contact. Returns how
+/// many rows were scored.
+pub async fn rescore_contacts(pool: &PgPool) -> ApiResult<usize> {
+    let config = get_scoring_config(pool).await?.config;
+    let contacts = sqlx::query_as!(ContactSubmission, r#"SELECT * FROM contact_submissions"#)
+        .fetch_all(pool)
+        .await?;
+
+    // Same visitors as `contact_engagement`: the person's, plus the one sent
+    // with the submission
+    let mut engagement: HashMap<Uuid, Engagement> = HashMap::new();
+    let page_views = sqlx::query!(
+        r#"
+        WITH contact_visitors AS (
+            SELECT c.id AS contact_id, pv.visitor_id
+            FROM contact_submissions c JOIN person_visitors pv ON pv.person_id = c.person_id
+            UNION
+            SELECT id, visitor_id FROM contact_submissions WHERE visitor_id IS NOT NULL
+        )
+        SELECT cv.contact_id as "contact_id!", COUNT(*) as "count!"
+        FROM contact_visitors cv JOIN analytics_page_views v ON v.visitor_id = cv.visitor_id
+        GROUP BY cv.contact_id
+        "#
+    )
+    .fetch_all(pool)
+    .await?;
+    for row in page_views {
+        engagement.entry(row.contact_id).or_default().page_views = row.count;
+    }
+    let events = sqlx::query!(
+        r#"
+        WITH contact_visitors AS (
+            SELECT c.id AS contact_id, pv.visitor_id
+            FROM contact_submissions c JOIN person_visitors pv ON pv.person_id = c.person_id
+            UNION
+            SELECT id, visitor_id FROM contact_submissions WHERE visitor_id IS NOT NULL
+        )
+        SELECT cv.contact_id as "contact_id!", e.event_name, COUNT(*) as "count!"
+        FROM contact_visitors cv JOIN analytics_events e ON e.visitor_id = cv.visitor_id
+        GROUP BY cv.contact_id, e.event_name
+        "#
+    )
+    .fetch_all(pool)
+    .await?;
+    for row in events {
+        engagement
+            .entry(row.contact_id)
+            .or_default()
+            .events
+            .insert(row.event_name, row.count);
+    }
+
+    let none = Engagement::default();
+    let mut ids = Vec::with_capacity(contacts.len());
+    let mut scores = Vec::with_capacity(contacts.len());
+    let mut explanations = Vec::with_capacity(contacts.len());
+    for contact in &contacts {
+        let activity = engagement.get(&contact.id).unwrap_or(&none);
+        let LeadScore { score, explanation } = scoring::score_contact(&config, contact, activity);
+        ids.push(contact.id);
+        scores.push(score);
+        explanations.push(explanation);
+    }
+    sqlx::query!(
+        r#"
+        UPDATE contact_submissions c
+        SET score = s.score, score_explanation = s.explanation, scored_at = NOW()
+        FROM UNNEST($1::uuid[], $2::int[], $3::text[]) AS s(id, score, explanation)
+        WHERE c.id = s.id
+        "#,
+        &ids,
+        &scores,
+        &explanations,
+    )
+    .execute(pool)
+    .await?;
+    Ok(contacts.len())
+}
+
 // ── Rate Limiting ────────────────────────────
 
 pub async fn count_recent_contacts(pool: &PgPool, email: &str, minutes: i64) -> ApiResult<i64> {
@@ -201,9 +2905,12 @@ pub async fn record_event(pool: &PgPool, req: &TrackEventRequest) -> ApiResult<(
 }
 
 pub async fn list_experiments(pool: &PgPool) -> ApiResult<Vec<Experiment>> {
-    let exps = sqlx::query_as!(Experiment, "SELECT * FROM experiments ORDER BY created_at DESC")
-        .fetch_all(pool)
-        .await?;
+    let exps = sqlx::query_as!(
+        Experiment,
+        "SELECT * FROM experiments ORDER BY created_at DESC"
+    )
+    .fetch_all(pool)
+    .await?;
     Ok(exps)
 }
 
@@ -0,0 +1,181 @@
+// XML sitemap builders (sitemaps.org 0.9). Handlers decide what goes in;
+// this module only renders and escapes.
+
+use chrono::{DateTime, SecondsFormat, Utc};
+
+use crate::feeds::xml_escape;
+
+/// Protocol limit on URLs per sitemap file; past this we serve an index.
+pub const MAX_URLS_PER_SITEMAP: i64 = 50_000;
+
+pub struct SitemapUrl {
+    pub loc: String,
+    pub lastmod: Option<DateTime<Utc>>,
+    pub changefreq: &'static str,
+    pub priority: &'static str,
+}
+
+/// Marketing pages listed ahead of blog posts in the first sitemap.
+pub fn static_pages(base_url: &str) -> Vec<SitemapUrl> {
+    let base = base_url.trim_end_matches('/');
+    [
+        ("/", "weekly", "1.0"),
+        ("/blog", "weekly", "0.8"),
+        ("/contact", "monthly", "0.7"),
+    ]
+    .into_iter()
+    .map(|(path, changefreq, priority)| SitemapUrl {
+        loc: format!("{base}{path}"),
+        lastmod: None,
+        changefreq,
+        priority,
+    })
+    .collect()
+}
+
+pub fn blog_post_url(base_url: &str, slug: &str, updated_at: DateTime<Utc>) -> SitemapUrl {
+    SitemapUrl {
+        loc: format!("{}/blog/{slug}", base_url.trim_end_matches('/')),
+        lastmod: Some(updated_at),
+        changefreq: "monthly",
+        priority: "0.6",
+    }
+}
+
+/// Child sitemaps needed to list `total_urls` URLs.
+pub fn page_count(total_urls: i64) -> i64 {
+    ((total_urls + MAX_URLS_PER_SITEMAP - 1) / MAX_URLS_PER_SITEMAP).max(1)
+}
+
+/// Offset and limit into the newest-first post list for 1-based `page`. The
+/// `static_count` static pages open page 1 and push every later page along.
+pub fn post_window(page: i64, static_count: i64) -> (i64, i64) {
+    if page == 1 {
+        (0, MAX_URLS_PER_SITEMAP - static_count)
+    } else {
+        (
+            (page - 1) * MAX_URLS_PER_SITEMAP - static_count,
+            MAX_URLS_PER_SITEMAP,
+        )
+    }
+}
+
+pub fn urlset(urls: &[SitemapUrl]) -> String {
+    let mut xml = String::from(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <urlset xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\">\n",
+    );
+    for url in urls {
+        xml.push_str("  <url><loc>");
+        xml.push_str(&xml_escape(&url.loc));
+        xml.push_str("</loc>");
+        if let Some(lastmod) = url.lastmod {
+            xml.push_str(&format!("<lastmod>{}</lastmod>", w3c_datetime(lastmod)));
+        }
+        xml.push_str(&format!(
+            "<changefreq>{}</changefreq><priority>{}</priority></url>\n",
+            url.changefreq, url.priority
+        ));
+    }
+    xml.push_str("</urlset>\n");
+    xml
+}
+
+/// Sitemap index pointing at `pages` child sitemaps under `{api_base}/sitemaps/{n}.xml`.
+pub fn index(api_base: &str, pages: i64, lastmod: Option<DateTime<Utc>>) -> String {
+    let base = api_base.trim_end_matches('/');
+    let mut xml = String::from(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <sitemapindex xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\">\n",
+    );
+    for page in 1..=pages {
+        xml.push_str(&format!(
+            "  <sitemap><loc>{}</loc>",
+            xml_escape(&format!("{base}/sitemaps/{page}.xml"))
+        ));
+        if let Some(lastmod) = lastmod {
+            xml.push_str(&format!("<lastmod>{}</lastmod>", w3c_datetime(lastmod)));
+        }
+        xml.push_str("</sitemap>\n");
+    }
+    xml.push_str("</sitemapindex>\n");
+    xml
+}
+
+fn w3c_datetime(dt: DateTime<Utc>) -> String {
+    dt.to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    #[test]
+    fn urls_are_xml_escaped() {
+        let url = blog_post_url("https://example.com/", "crews-&-<costs>", Utc::now());
+        let xml = urlset(&[url]);
+        let loc = "<loc>https://example.com/blog/crews-&amp;-&lt;costs&gt;</loc>";
+        assert!(xml.contains(loc), "{xml}");
+
+        let xml = index("https://example.com/r&d/api/v1", 1, None);
+        let loc = "<loc>https://example.com/r&amp;d/api/v1/sitemaps/1.xml</loc>";
+        assert!(xml.contains(loc), "{xml}");
+    }
+
+    #[test]
+    fn lastmod_is_w3c_datetime_in_utc() {
+        let at = Utc.with_ymd_and_hms(2026, 3, 9, 7, 5, 0).unwrap();
+        assert_eq!(w3c_datetime(at), "2026-03-09T07:05:00Z");
+
+        let xml = urlset(&[blog_post_url("https://example.com", "a", at)]);
+        assert!(
+            xml.contains("<lastmod>2026-03-09T07:05:00Z</lastmod>"),
+            "{xml}"
+        );
+        let xml = urlset(&static_pages("https://example.com"));
+        assert!(!xml.contains("<lastmod>"), "{xml}");
+    }
+
+    #[test]
+    fn index_lists_one_sitemap_per_page() {
+        let at = Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap();
+        let xml = index("https://example.com/api/v1/", 3, Some(at));
+        assert_eq!(xml.matches("<sitemap>").count(), 3);
+        let last = concat!(
+            "<loc>https://example.com/api/v1/sitemaps/3.xml</loc>",
+            "<lastmod>2026-01-01T00:00:00Z</lastmod>",
+        );
+        assert!(xml.contains(last), "{xml}");
+        assert!(!xml.contains("sitemaps/4.xml"));
+    }
+
+    #[test]
+    fn splits_into_pages_past_the_protocol_limit() {
+        let cases = [
+            (0, 1),
+            (1, 1),
+            (50_000, 1),
+            (50_001, 2),
+            (100_000, 2),
+            (100_001, 3),
+        ];
+        for (total, pages) in cases {
+            assert_eq!(page_count(total), pages, "{total} URLs");
+        }
+    }
+
+    #[test]
+    fn posts_fill_each_page_after_the_static_pages() {
+        assert_eq!(post_window(1, 3), (0, 49_997));
+        assert_eq!(post_window(2, 3), (49_997, 50_000));
+        assert_eq!(post_window(3, 3), (99_997, 50_000));
+
+        // Consecutive pages cover the post list with no gaps or overlaps
+        for page in 1..5 {
+            let (offset, limit) = post_window(page, 3);
+            assert_eq!(offset + limit, post_window(page + 1, 3).0);
+        }
+    }
+}
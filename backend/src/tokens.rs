@@ -0,0 +1,201 @@
+// Signed, expiring tokens for links we email to people (waitlist confirmation,
+// unsubscribe, data erasure and access exports). A token is
+// `subject.expires.signature`: the subject is base64url, `expires` is a Unix
+// timestamp, and the signature is an HMAC-SHA256 over purpose, subject and
+// expiry so a token minted for one flow is useless in another.
+
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use chrono::{Duration, Utc};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+type HmacSha256 = Hmac<Sha256>;
+
+#[derive(Debug, Clone, Copy)]
+pub enum Purpose {
+    WaitlistConfirm,
+    Unsubscribe,
+    DataErasure,
+    DataAccess,
+}
+
+impl Purpose {
+    fn as_str(self) -> &'static str {
+        match self {
+            Purpose::WaitlistConfirm => "waitlist_confirm",
+            Purpose::Unsubscribe => "unsubscribe",
+            Purpose::DataErasure => "data_erasure",
+            Purpose::DataAccess => "data_access",
+        }
+    }
+
+    pub fn ttl(self) -> Duration {
+        match self {
+            Purpose::WaitlistConfirm => Duration::days(7),
+            // Has to keep working from old emails in someone's inbox
+            Purpose::Unsubscribe => Duration::days(365),
+            Purpose::DataErasure => Duration::hours(24),
+            Purpose::DataAccess => Duration::hours(72),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum TokenError {
+    Invalid,
+    Expired,
+}
+
+pub fn sign(secret: &str, purpose: Purpose, subject: &str) -> String {
+    let expires = (Utc::now() + purpose.ttl()).timestamp();
+    let subject = URL_SAFE_NO_PAD.encode(subject);
+    let signature = URL_SAFE_NO_PAD.encode(
+        mac(secret, purpose, &subject, expires)
+            .finalize()
+            .into_bytes(),
+    );
+    format!("{subject}.{expires}.{signature}")
+}
+
+/// Check signature and expiry; returns the subject the token was signed for.
+pub fn verify(secret: &str, purpose: Purpose, token: &str) -> Result<String, TokenError> {
+    let mut parts = token.trim().split('.');
+    let (Some(subject), Some(expires), Some(signature), None) =
+        (parts.next(), parts.next(), parts.next(), parts.next())
+    else {
+        return Err(TokenError::Invalid);
+    };
+    let expires: i64 = expires.parse().map_err(|_| TokenError::Invalid)?;
+    let signature = URL_SAFE_NO_PAD
+        .decode(signature)
+        .map_err(|_| TokenError::Invalid)?;
+
+    mac(secret, purpose, subject, expires)
+        .verify_slice(&signature)
+        .map_err(|_| TokenError::Invalid)?;
+    if Utc::now().timestamp() > expires {
+        return Err(TokenError::Expired);
+    }
+
+    let subject = URL_SAFE_NO_PAD
+        .decode(subject)
+        .map_err(|_| TokenError::Invalid)?;
+    String::from_utf8(subject).map_err(|_| TokenError::Invalid)
+}
+
+fn mac(secret: &str, purpose: Purpose, subject: &str, expires: i64) -> HmacSha256 {
+    // HMAC accepts keys of any length
+    let mut mac = HmacSha256::new_from_slice(secret.as_bytes()).expect("HMAC key");
+    mac.update(purpose.as_str().as_bytes());
+    mac.update(b"\0");
+    mac.update(subject.as_bytes());
+    mac.update(b"\0");
+    mac.update(expires.to_string().as_bytes());
+    mac
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECRET: &str = "test-secret";
+
+    /// A token as `sign` would make it, but with a chosen expiry.
+    fn token_expiring(purpose: Purpose, subject: &str, expires: i64) -> String {
+        let subject = URL_SAFE_NO_PAD.encode(subject);
+        let signature = mac(SECRET, purpose, &subject, expires)
+            .finalize()
+            .into_bytes();
+        format!("{subject}.{expires}.{}", URL_SAFE_NO_PAD.encode(signature))
+    }
+
+    #[test]
+    fn round_trips_the_subject() {
+        let token = sign(SECRET, Purpose::Unsubscribe, "grower@example.com");
+        let subject = verify(SECRET, Purpose::Unsubscribe, &token);
+        assert_eq!(subject, Ok("grower@example.com".to_string()));
+        // Pasted links often pick up stray whitespace
+        assert!(verify(SECRET, Purpose::Unsubscribe, &format!(" {token}\n")).is_ok());
+    }
+
+    #[test]
+    fn expired_tokens_are_rejected() {
+        let past = (Utc::now() - Duration::minutes(1)).timestamp();
+        let token = token_expiring(Purpose::DataErasure, "a@example.com", past);
+        assert_eq!(
+            verify(SECRET, Purpose::DataErasure, &token),
+            Err(TokenError::Expired)
+        );
+
+        let future = (Utc::now() + Duration::minutes(1)).timestamp();
+        let token = token_expiring(Purpose::DataErasure, "a@example.com", future);
+        assert!(verify(SECRET, Purpose::DataErasure, &token).is_ok());
+    }
+
+    #[test]
+    fn a_token_only_works_for_its_purpose() {
+        let token = sign(SECRET, Purpose::Unsubscribe, "a@example.com");
+        for purpose in [
+            Purpose::WaitlistConfirm,
+            Purpose::DataErasure,
+            Purpose::DataAccess,
+        ] {
+            assert_eq!(verify(SECRET, purpose, &token), Err(TokenError::Invalid));
+        }
+    }
+
+    #[test]
+    fn tampering_breaks_the_signature() {
+        let token = sign(SECRET, Purpose::DataAccess, "a@example.com");
+        let parts: Vec<&str> = token.split('.').collect();
+
+        let other_subject = URL_SAFE_NO_PAD.encode("b@example.com");
+        let swapped = format!("{other_subject}.{}.{}", parts[1], parts[2]);
+        assert_eq!(
+            verify(SECRET, Purpose::DataAccess, &swapped),
+            Err(TokenError::Invalid)
+        );
+
+        let later = parts[1].parse::<i64>().unwrap() + 86_400;
+        let extended = format!("{}.{later}.{}", parts[0], parts[2]);
+        assert_eq!(
+            verify(SECRET, Purpose::DataAccess, &extended),
+            Err(TokenError::Invalid)
+        );
+
+        let mut signature = URL_SAFE_NO_PAD.decode(parts[2]).unwrap();
+        signature[0] ^= 1;
+        let flipped = format!(
+            "{}.{}.{}",
+            parts[0],
+            parts[1],
+            URL_SAFE_NO_PAD.encode(signature)
+        );
+        assert_eq!(
+            verify(SECRET, Purpose::DataAccess, &flipped),
+            Err(TokenError::Invalid)
+        );
+
+        assert_eq!(
+            verify("other-secret", Purpose::DataAccess, &token),
+            Err(TokenError::Invalid)
+        );
+    }
+
+    #[test]
+    fn malformed_tokens_are_invalid() {
+        let token = sign(SECRET, Purpose::WaitlistConfirm, "id");
+        for bad in [
+            String::new(),
+            "garbage".to_string(),
+            "a.b".to_string(),
+            format!("{token}.extra"),
+            token.replacen('.', ".not-a-number", 1),
+            format!("{token}!"),
+        ] {
+            let result = verify(SECRET, Purpose::WaitlistConfirm, &bad);
+            assert_eq!(result, Err(TokenError::Invalid), "{bad:?}");
+        }
+    }
+}
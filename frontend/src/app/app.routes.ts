@@ -21,6 +21,26 @@ export const routes: Routes = [
     loadComponent: () =>
       import('./pages/contact/contact.component').then((m) => m.ContactComponent),
   },
+  {
+    path: 'waitlist/confirm',
+    loadComponent: () =>
+      import('./pages/waitlist-confirm/waitlist-confirm.component').then((m) => m.WaitlistConfirmComponent),
+  },
+  {
+    path: 'unsubscribe',
+    loadComponent: () =>
+      import('./pages/unsubscribe/unsubscribe.component').then((m) => m.UnsubscribeComponent),
+  },
+  {
+    path: 'privacy/delete',
+    loadComponent: () =>
+      import('./pages/delete-data/delete-data.component').then((m) => m.DeleteDataComponent),
+  },
+  {
+    path: 'privacy/export',
+    loadComponent: () =>
+      import('./pages/export-data/export-data.component').then((m) => m.ExportDataComponent),
+  },
   {
     path: 'admin',
     loadComponent: () =>
@@ -139,6 +139,7 @@ export class ContactFormComponent {
       crop_type: this.cropType || undefined,
       message: this.message,
       source: 'website_contact',
+      visitor_id: this.analytics.currentVisitorId,
       website: this.website || undefined,
       _form_loaded_at: this.formLoadedAt,
     }).subscribe({
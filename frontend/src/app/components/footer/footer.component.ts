@@ -29,6 +29,8 @@ import { RouterLink } from '@angular/router';
           <a routerLink="/contact">Contact</a>
           <a href="#">Careers</a>
           <a href="#">Privacy Policy</a>
+          <a routerLink="/privacy/export">Download My Data</a>
+          <a routerLink="/privacy/delete">Delete My Data</a>
         </div>
       </div>
       <div class="container footer-bottom">
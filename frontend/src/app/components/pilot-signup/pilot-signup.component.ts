@@ -1,8 +1,10 @@
-import { Component, signal, inject } from '@angular/core';
+import { Component, signal, inject, OnInit } from '@angular/core';
 import { FormsModule } from '@angular/forms';
 import { ApiService } from '@app/services/api.service';
 import { AnalyticsService } from '@app/services/analytics.service';
 
+const REFERRAL_KEY = 'terroir_ref';
+
 @Component({
   selector: 'app-pilot-signup',
   standalone: true,
@@ -12,8 +14,8 @@ import { AnalyticsService } from '@app/services/analytics.service';
       @if (submitted()) {
         <div class="success animate-fade-up">
           <div class="success-icon">&#10003;</div>
-          <h3>You're on the list!</h3>
-          <p>We're selecting 20 farms for the 2026 pilot. We'll reach out within 48 hours.</p>
+          <h3>Thanks for signing up!</h3>
+          <p>{{ confirmationMessage() }}</p>
           <div class="share-prompt">
             <p class="share-text">Know another grower who'd benefit?</p>
             <div class="share-actions">
@@ -171,7 +173,7 @@ import { AnalyticsService } from '@app/services/analytics.service';
     }
   `],
 })
-export class PilotSignupComponent {
+export class PilotSignupComponent implements OnInit {
   private readonly api = inject(ApiService);
   private readonly analytics = inject(AnalyticsService);
 
@@ -184,8 +186,18 @@ export class PilotSignupComponent {
 
   loading = signal(false);
   submitted = signal(false);
+  confirmationMessage = signal('');
   error = signal('');
   copied = signal(false);
+  private referredBy: string | undefined;
+
+  ngOnInit() {
+    if (typeof window === 'undefined') return;
+    // Keep the referrer's code for the session so it survives navigating around before signing up
+    const ref = new URLSearchParams(window.location.search).get('ref');
+    if (ref) sessionStorage.setItem(REFERRAL_KEY, ref);
+    this.referredBy = sessionStorage.getItem(REFERRAL_KEY) ?? undefined;
+  }
 
   onSubmit() {
     if (this.loading()) return;
@@ -197,11 +209,14 @@ export class PilotSignupComponent {
       name: this.name || undefined,
       company: this.company || undefined,
       interest: 'pilot_2026',
+      ref: this.referredBy,
+      visitor_id: this.analytics.currentVisitorId,
       website: this.website || undefined,
       _form_loaded_at: this.formLoadedAt,
     }).subscribe({
-      next: () => {
+      next: (res) => {
         this.loading.set(false);
+        this.confirmationMessage.set(res.message);
         this.submitted.set(true);
         this.analytics.trackEvent(
           'pilot_signup', 'activation', 'pilot_2026', undefined,
@@ -220,16 +235,20 @@ export class PilotSignupComponent {
     });
   }
 
+  private get shareUrl(): string {
+    return 'https://terroirai.com/?utm_source=referral&utm_medium=pilot_share&utm_campaign=pilot_2026';
+  }
+
   onShare(method: string) {
     this.analytics.trackShare(method);
     if (method === 'copy') {
-      navigator.clipboard?.writeText('https://terroirai.com?utm_source=referral&utm_medium=pilot_share&utm_campaign=pilot_2026');
+      navigator.clipboard?.writeText(this.shareUrl);
       this.copied.set(true);
       setTimeout(() => this.copied.set(false), 2000);
     } else if (method === 'email') {
       const subject = encodeURIComponent('Check out Terroir AI — Precision Labor Intelligence');
       const body = encodeURIComponent(
-        'I just signed up for their 2026 pilot program for precision labor intelligence. Thought you might be interested:\nhttps://terroirai.com?utm_source=referral&utm_medium=pilot_share&utm_campaign=pilot_2026'
+        `I just signed up for their 2026 pilot program for precision labor intelligence. Thought you might be interested:\n${this.shareUrl}`
       );
       window.open(`mailto:?subject=${subject}&body=${body}`);
     }
@@ -1,18 +1,21 @@
 import { Component, inject, signal, OnInit } from '@angular/core';
 import { FormsModule } from '@angular/forms';
-import { DatePipe, SlicePipe } from '@angular/common';
+import { DatePipe, PercentPipe, SlicePipe } from '@angular/common';
 import { SeoService } from '../../services/seo.service';
 import {
   ApiService,
   AdminStats,
+  CohortFill,
   ContactSubmission,
+  OutboxEmail,
+  OutboxStats,
   WaitlistEntry,
 } from '../../services/api.service';
 
 @Component({
   selector: 'app-admin',
   standalone: true,
-  imports: [FormsModule, DatePipe, SlicePipe],
+  imports: [FormsModule, DatePipe, PercentPipe, SlicePipe],
   template: `
     @if (!authenticated()) {
       <section class="admin-login">
@@ -65,7 +68,16 @@ import {
             </div>
           }
 
+          <form class="search-bar" (ngSubmit)="search()">
+            <input type="search" [(ngModel)]="query" name="q" placeholder="Search name, email or company">
+            <button type="submit" class="btn btn-outline">Search</button>
+          </form>
+
           <h2>Contact Submissions</h2>
+          <div class="export-actions">
+            <button type="button" class="btn btn-outline" (click)="download('contacts', 'csv')">Export CSV</button>
+            <button type="button" class="btn btn-outline" (click)="download('contacts', 'xlsx')">Export XLSX</button>
+          </div>
           @if (contacts().length === 0) {
             <p class="empty">No contact submissions yet.</p>
           } @else {
@@ -97,9 +109,54 @@ import {
                 </tbody>
               </table>
             </div>
+            @if (contactsCursor()) {
+              <button type="button" class="btn btn-ghost" (click)="loadContacts(true)">Load more</button>
+            }
+          }
+
+          <h2>Pilot Cohorts</h2>
+          @if (cohorts().length === 0) {
+            <p class="empty">No pilot cohorts yet.</p>
+          } @else {
+            <div class="table-wrap">
+              <table>
+                <thead>
+                  <tr>
+                    <th>Cohort</th>
+                    <th>Region</th>
+                    <th>Crop</th>
+                    <th>Starts</th>
+                    <th>Accepted</th>
+                    <th>Invited</th>
+                    <th>Waitlisted</th>
+                    <th>Open Seats</th>
+                    <th>Fill</th>
+                  </tr>
+                </thead>
+                <tbody>
+                  @for (c of cohorts(); track c.id) {
+                    <tr>
+                      <td>{{ c.name }}</td>
+                      <td>{{ c.region || '—' }}</td>
+                      <td>{{ c.crop_focus || '—' }}</td>
+                      <td class="nowrap">{{ c.starts_on ? (c.starts_on | date:'mediumDate') : '—' }}</td>
+                      <td>{{ c.accepted }} / {{ c.capacity }}</td>
+                      <td>{{ c.invited }}</td>
+                      <td>{{ c.waitlisted }}</td>
+                      <td>{{ c.seats_remaining }}</td>
+                      <td>{{ c.fill_rate | percent }}</td>
+                    </tr>
+                  }
+                </tbody>
+              </table>
+            </div>
           }
 
           <h2>Waitlist / Pilot Signups</h2>
+          <div class="export-actions">
+            <button type="button" class="btn btn-outline" (click)="download('waitlist', 'csv')">Export CSV</button>
+            <button type="button" class="btn btn-outline" (click)="download('waitlist', 'xlsx')">Export XLSX</button>
+          </div>
           @if (waitlist().length === 0) {
             <p class="empty">No waitlist entries yet.</p>
           } @else {
@@ -112,6 +169,7 @@ import {
                     <th>Name</th>
                     <th>Company</th>
                     <th>Interest</th>
+                    <th>Pilot</th>
                   </tr>
                 </thead>
                 <tbody>
@@ -122,6 +180,52 @@ import {
                       <td>{{ w.name || '—' }}</td>
                       <td>{{ w.company || '—' }}</td>
                       <td>{{ w.interest }}</td>
+                      <td>{{ w.pilot_status }}</td>
+                    </tr>
+                  }
+                </tbody>
+              </table>
+            </div>
+            @if (waitlistCursor()) {
+              <button type="button" class="btn btn-ghost" (click)="loadWaitlist(true)">Load more</button>
+            }
+          }
+
+          <h2>Email Delivery</h2>
+          @if (outboxStats(); as o) {
+            <p class="outbox-summary">
+              {{ o.sent }} sent · {{ o.pending }} queued
+              @if (o.retrying) { ({{ o.retrying }} retrying) }
+              · {{ o.dead }} failed
+              @if (o.oldest_pending_at) { · oldest queued {{ o.oldest_pending_at | date:'short' }} }
+            </p>
+          }
+          @if (failedEmails().length === 0) {
+            <p class="empty">No failed emails.</p>
+          } @else {
+            <div class="table-wrap">
+              <table>
+                <thead>
+                  <tr>
+                    <th>Queued</th>
+                    <th>To</th>
+                    <th>Subject</th>
+                    <th>Attempts</th>
+                    <th>Error</th>
+                    <th></th>
+                  </tr>
+                </thead>
+                <tbody>
+                  @for (e of failedEmails(); track e.id) {
+                    <tr>
+                      <td class="nowrap">{{ e.created_at | date:'short' }}</td>
+                      <td>{{ e.recipient }}</td>
+                      <td>{{ e.subject }}</td>
+                      <td>{{ e.attempts }}</td>
+                      <td class="msg-cell" [title]="e.last_error || ''">{{ e.last_error || '—' }}</td>
+                      <td>
+                        <button type="button" class="btn btn-ghost" (click)="retryEmail(e)">Retry</button>
+                      </td>
                     </tr>
                   }
                 </tbody>
@@ -171,6 +275,9 @@ import {
 
     h2 { font-family: var(--font-sans); font-size: 1.25rem; margin: 2rem 0 1rem; }
     .table-wrap { overflow-x: auto; margin-bottom: 2rem; }
+    .export-actions { display: flex; gap: 0.5rem; margin-bottom: 1rem; }
+    .search-bar { display: flex; gap: 0.5rem; margin-bottom: 2rem; }
+    .search-bar input { flex: 1; }
     table { width: 100%; border-collapse: collapse; font-size: 0.85rem; font-family: var(--font-sans); }
     th { text-align: left; font-size: 0.7rem; font-weight: 600; text-transform: uppercase; letter-spacing: 0.05em; color: var(--stone-400); padding: 0.75rem 0.5rem; border-bottom: 2px solid var(--stone-200); }
     td { padding: 0.65rem 0.5rem; border-bottom: 1px solid var(--stone-100); color: var(--stone-700); }
@@ -179,6 +286,7 @@ import {
     .nowrap { white-space: nowrap; }
     .msg-cell { max-width: 250px; overflow: hidden; text-overflow: ellipsis; white-space: nowrap; }
     .empty { color: var(--stone-400); padding: 1.5rem 0; }
+    .outbox-summary { font-family: var(--font-sans); font-size: 0.85rem; color: var(--stone-500); margin-bottom: 1rem; }
 
     @media (max-width: 768px) {
       .stats-grid { grid-template-columns: repeat(2, 1fr); }
@@ -195,6 +303,12 @@ export class AdminComponent implements OnInit {
   stats = signal<AdminStats | null>(null);
   contacts = signal<ContactSubmission[]>([]);
   waitlist = signal<WaitlistEntry[]>([]);
+  cohorts = signal<CohortFill[]>([]);
+  outboxStats = signal<OutboxStats | null>(null);
+  failedEmails = signal<OutboxEmail[]>([]);
+  contactsCursor = signal<string | null>(null);
+  waitlistCursor = signal<string | null>(null);
+  query = '';
 
   private token = '';
 
@@ -228,6 +342,8 @@ export class AdminComponent implements OnInit {
         }
         this.loadContacts();
         this.loadWaitlist();
+        this.loadCohorts();
+        this.loadOutbox();
       },
       error: () => {
         this.authError.set(true);
@@ -242,6 +358,8 @@ export class AdminComponent implements OnInit {
         this.stats.set(data);
         this.loadContacts();
         this.loadWaitlist();
+        this.loadCohorts();
+        this.loadOutbox();
       },
       error: () => {
         if (typeof window !== 'undefined') {
@@ -251,15 +369,62 @@ export class AdminComponent implements OnInit {
     });
   }
 
-  private loadContacts() {
-    this.api.getAdminContacts(this.token).subscribe({
-      next: (data) => this.contacts.set(data),
+  search() {
+    this.loadContacts();
+    this.loadWaitlist();
+  }
+
+  loadContacts(more = false) {
+    const cursor = more ? this.contactsCursor() ?? undefined : undefined;
+    this.api.getAdminContacts(this.token, { q: this.query, cursor }).subscribe({
+      next: (page) => {
+        this.contacts.set(more ? [...this.contacts(), ...page.items] : page.items);
+        this.contactsCursor.set(page.next_cursor);
+      },
+    });
+  }
+
+  download(table: 'contacts' | 'waitlist', format: 'csv' | 'xlsx') {
+    this.api.exportAdmin(this.token, table, format).subscribe({
+      next: (blob) => {
+        const url = URL.createObjectURL(blob);
+        const link = document.createElement('a');
+        link.href = url;
+        link.download = `${table}-${new Date().toISOString().slice(0, 10)}.${format}`;
+        link.click();
+        URL.revokeObjectURL(url);
+      },
+    });
+  }
+
+  loadCohorts() {
+    this.api.getAdminCohorts(this.token).subscribe({
+      next: (cohorts) => this.cohorts.set(cohorts),
+    });
+  }
+
+  loadOutbox() {
+    this.api.getOutboxStats(this.token).subscribe({
+      next: (stats) => this.outboxStats.set(stats),
+    });
+    this.api.getOutbox(this.token, 'dead').subscribe({
+      next: (emails) => this.failedEmails.set(emails),
     });
   }
 
-  private loadWaitlist() {
-    this.api.getAdminWaitlist(this.token).subscribe({
-      next: (data) => this.waitlist.set(data),
+  retryEmail(email: OutboxEmail) {
+    this.api.retryEmail(this.token, email.id).subscribe({
+      next: () => this.loadOutbox(),
+    });
+  }
+
+  loadWaitlist(more = false) {
+    const cursor = more ? this.waitlistCursor() ?? undefined : undefined;
+    this.api.getAdminWaitlist(this.token, { q: this.query, cursor }).subscribe({
+      next: (page) => {
+        this.waitlist.set(more ? [...this.waitlist(), ...page.items] : page.items);
+        this.waitlistCursor.set(page.next_cursor);
+      },
     });
   }
 }
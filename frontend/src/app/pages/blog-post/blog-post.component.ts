@@ -1,7 +1,8 @@
-import { Component, inject, signal, OnInit } from '@angular/core';
+import { Component, inject, signal, OnInit, HostListener } from '@angular/core';
 import { ActivatedRoute, RouterLink } from '@angular/router';
 import { ApiService, BlogPost } from '@app/services/api.service';
 import { SeoService } from '@app/services/seo.service';
+import { AnalyticsService } from '@app/services/analytics.service';
 import { DatePipe } from '@angular/common';
 
 @Component({
@@ -316,10 +317,29 @@ export class BlogPostComponent implements OnInit {
   private readonly route = inject(ActivatedRoute);
   private readonly api = inject(ApiService);
   private readonly seo = inject(SeoService);
+  private readonly analytics = inject(AnalyticsService);
 
   post = signal<BlogPost | null>(null);
   loading = signal(true);
 
+  /** Scroll-depth milestones already reported for this post */
+  private readonly readMilestones = new Set<number>();
+
+  @HostListener('window:scroll')
+  onScroll() {
+    const post = this.post();
+    if (!post) return;
+
+    const scrollable = document.documentElement.scrollHeight - window.innerHeight;
+    const depth = scrollable > 0 ? Math.round((window.scrollY / scrollable) * 100) : 100;
+    for (const milestone of [25, 50, 75, 100]) {
+      if (depth >= milestone && !this.readMilestones.has(milestone)) {
+        this.readMilestones.add(milestone);
+        this.analytics.trackBlogRead(post.slug, milestone);
+      }
+    }
+  }
+
   ngOnInit() {
     const slug = this.route.snapshot.paramMap.get('slug') ?? '';
     this.api.getBlogPost(slug).subscribe({
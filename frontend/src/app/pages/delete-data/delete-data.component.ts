@@ -0,0 +1,119 @@
+import { Component, inject, signal, OnInit } from '@angular/core';
+import { FormsModule } from '@angular/forms';
+import { ActivatedRoute } from '@angular/router';
+import { ApiService } from '@app/services/api.service';
+import { AnalyticsService } from '@app/services/analytics.service';
+import { SeoService } from '@app/services/seo.service';
+
+/**
+ * Two steps: without a token, ask for an email and send it a confirmation
+ * link; with one, delete on an explicit click (never on page load, so link
+ * scanners and prefetchers can't trigger it).
+ */
+@Component({
+  selector: 'app-delete-data',
+  standalone: true,
+  imports: [FormsModule],
+  template: `
+    <section class="delete-data" aria-label="Delete my data">
+      <div class="container">
+        <h1>Delete my data</h1>
+        @if (token) {
+          @if (state() === 'done') {
+            <p>{{ message() }}</p>
+          } @else {
+            <p>
+              This permanently deletes your contact form submissions, waitlist signups and the site
+              analytics linked to them. It can't be undone.
+            </p>
+            <button type="button" class="btn btn-primary" [disabled]="loading()" (click)="confirm()">
+              {{ loading() ? 'Deleting…' : 'Delete my data' }}
+            </button>
+          }
+        } @else if (state() === 'done') {
+          <p>{{ message() }}</p>
+        } @else {
+          <p>Enter the email you used with us. We'll send a link to that address to confirm the deletion.</p>
+          <form (ngSubmit)="request()">
+            <input type="email" name="email" [(ngModel)]="email" placeholder="you@farm.com" required />
+            <button type="submit" class="btn btn-primary" [disabled]="loading() || !email">
+              {{ loading() ? 'Sending…' : 'Send confirmation link' }}
+            </button>
+          </form>
+        }
+        @if (state() === 'failed') {
+          <p class="error-msg">{{ message() }}</p>
+        }
+      </div>
+    </section>
+  `,
+  styles: [`
+    .delete-data { padding: 8rem 0 6rem; text-align: center; }
+    .delete-data .container { max-width: 560px; }
+    .delete-data h1 { font-size: clamp(1.75rem, 4vw, 2.5rem); margin-bottom: 0.75rem; }
+    .delete-data p { color: var(--stone-500); font-family: var(--font-serif); margin-bottom: 2rem; }
+    .delete-data form { display: flex; flex-direction: column; gap: 1rem; }
+    .delete-data input {
+      padding: 0.65rem 0.85rem; border: 1px solid var(--stone-300);
+      border-radius: 8px; font-size: 0.9rem; font-family: var(--font-sans);
+    }
+    .error-msg { color: #dc2626; font-size: 0.85rem; margin-top: 1rem; }
+  `],
+})
+export class DeleteDataComponent implements OnInit {
+  private readonly route = inject(ActivatedRoute);
+  private readonly api = inject(ApiService);
+  private readonly analytics = inject(AnalyticsService);
+  private readonly seo = inject(SeoService);
+
+  token: string | null = null;
+  email = '';
+  private formLoadedAt = Date.now();
+
+  state = signal<'idle' | 'done' | 'failed'>('idle');
+  loading = signal(false);
+  message = signal('');
+
+  ngOnInit() {
+    this.seo.updateSeo({
+      title: 'Delete my data',
+      description: 'Ask Terroir AI to delete the personal data we hold about you.',
+      url: '/privacy/delete',
+      noindex: true,
+    });
+    this.token = this.route.snapshot.queryParamMap.get('token');
+  }
+
+  request() {
+    if (this.loading()) return;
+    this.loading.set(true);
+    this.api.requestErasure(this.email, this.formLoadedAt).subscribe({
+      next: (res) => this.finish(res.message),
+      error: (err) => this.fail(err),
+    });
+  }
+
+  confirm() {
+    if (this.loading() || !this.token) return;
+    this.loading.set(true);
+    this.api.confirmErasure(this.token).subscribe({
+      next: (res) => {
+        this.analytics.resetVisitor();
+        this.finish(res.message);
+      },
+      error: (err) => this.fail(err),
+    });
+  }
+
+  private finish(message: string) {
+    this.loading.set(false);
+    this.state.set('done');
+    this.message.set(message);
+  }
+
+  private fail(err: { error?: { error?: string } }) {
+    this.loading.set(false);
+    this.state.set('failed');
+    this.message.set(err.error?.error ?? 'Something went wrong. Please try again.');
+  }
+}
@@ -0,0 +1,122 @@
+import { Component, inject, signal, OnInit } from '@angular/core';
+import { FormsModule } from '@angular/forms';
+import { ActivatedRoute } from '@angular/router';
+import { ApiService } from '@app/services/api.service';
+import { SeoService } from '@app/services/seo.service';
+
+/**
+ * Without a token, ask for an email and send it a download link; with one,
+ * offer the bundle as JSON or ZIP until the link expires.
+ */
+@Component({
+  selector: 'app-export-data',
+  standalone: true,
+  imports: [FormsModule],
+  template: `
+    <section class="export-data" aria-label="Download my data">
+      <div class="container">
+        <h1>Download my data</h1>
+        @if (token) {
+          <p>
+            Your export includes contact form submissions, waitlist signups and the site analytics
+            linked to them.
+          </p>
+          <div class="actions">
+            <button type="button" class="btn btn-primary" [disabled]="loading()" (click)="download('zip')">
+              Download ZIP
+            </button>
+            <button type="button" class="btn btn-outline" [disabled]="loading()" (click)="download('json')">
+              Download JSON
+            </button>
+          </div>
+        } @else if (state() === 'done') {
+          <p>{{ message() }}</p>
+        } @else {
+          <p>Enter the email you used with us. We'll send a download link to that address.</p>
+          <form (ngSubmit)="request()">
+            <input type="email" name="email" [(ngModel)]="email" placeholder="you@farm.com" required />
+            <button type="submit" class="btn btn-primary" [disabled]="loading() || !email">
+              {{ loading() ? 'Sending…' : 'Send download link' }}
+            </button>
+          </form>
+        }
+        @if (state() === 'failed') {
+          <p class="error-msg">{{ message() }}</p>
+        }
+      </div>
+    </section>
+  `,
+  styles: [`
+    .export-data { padding: 8rem 0 6rem; text-align: center; }
+    .export-data .container { max-width: 560px; }
+    .export-data h1 { font-size: clamp(1.75rem, 4vw, 2.5rem); margin-bottom: 0.75rem; }
+    .export-data p { color: var(--stone-500); font-family: var(--font-serif); margin-bottom: 2rem; }
+    .export-data form { display: flex; flex-direction: column; gap: 1rem; }
+    .export-data input {
+      padding: 0.65rem 0.85rem; border: 1px solid var(--stone-300);
+      border-radius: 8px; font-size: 0.9rem; font-family: var(--font-sans);
+    }
+    .actions { display: flex; gap: 0.75rem; justify-content: center; }
+    .error-msg { color: #dc2626; font-size: 0.85rem; margin-top: 1rem; }
+  `],
+})
+export class ExportDataComponent implements OnInit {
+  private readonly route = inject(ActivatedRoute);
+  private readonly api = inject(ApiService);
+  private readonly seo = inject(SeoService);
+
+  token: string | null = null;
+  email = '';
+  private formLoadedAt = Date.now();
+
+  state = signal<'idle' | 'done' | 'failed'>('idle');
+  loading = signal(false);
+  message = signal('');
+
+  ngOnInit() {
+    this.seo.updateSeo({
+      title: 'Download my data',
+      description: 'Get a copy of the personal data Terroir AI holds about you.',
+      url: '/privacy/export',
+      noindex: true,
+    });
+    this.token = this.route.snapshot.queryParamMap.get('token');
+  }
+
+  request() {
+    if (this.loading()) return;
+    this.loading.set(true);
+    this.api.requestAccessExport(this.email, this.formLoadedAt).subscribe({
+      next: (res) => {
+        this.loading.set(false);
+        this.state.set('done');
+        this.message.set(res.message);
+      },
+      error: (err) => this.fail(err.error?.error),
+    });
+  }
+
+  download(format: 'json' | 'zip') {
+    if (this.loading() || !this.token) return;
+    this.loading.set(true);
+    this.api.downloadAccessExport(this.token, format).subscribe({
+      next: (blob) => {
+        this.loading.set(false);
+        const url = URL.createObjectURL(blob);
+        const link = document.createElement('a');
+        link.href = url;
+        link.download = `terroir-data.${format}`;
+        link.click();
+        URL.revokeObjectURL(url);
+      },
+      // Error bodies arrive as a Blob here, so fall back to a generic message
+      error: () => this.fail('This download link is invalid or has expired.'),
+    });
+  }
+
+  private fail(message?: string) {
+    this.loading.set(false);
+    this.state.set('failed');
+    this.message.set(message ?? 'Something went wrong. Please try again.');
+  }
+}
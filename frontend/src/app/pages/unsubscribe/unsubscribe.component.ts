@@ -0,0 +1,67 @@
+import { Component, inject, signal, OnInit } from '@angular/core';
+import { ActivatedRoute, RouterLink } from '@angular/router';
+import { ApiService } from '@app/services/api.service';
+import { SeoService } from '@app/services/seo.service';
+
+@Component({
+  selector: 'app-unsubscribe',
+  standalone: true,
+  imports: [RouterLink],
+  template: `
+    <section class="unsubscribe" aria-label="Unsubscribe">
+      <div class="container">
+        @if (state() === 'pending') {
+          <p>Unsubscribing…</p>
+        } @else if (state() === 'done') {
+          <h1>You're unsubscribed</h1>
+          <p>{{ message() }}</p>
+          <a routerLink="/privacy/delete" class="btn btn-outline">Delete my data too</a>
+        } @else {
+          <h1>We couldn't unsubscribe you</h1>
+          <p>{{ message() }}</p>
+          <a routerLink="/contact" class="btn btn-primary">Contact us</a>
+        }
+      </div>
+    </section>
+  `,
+  styles: [`
+    .unsubscribe { padding: 8rem 0 6rem; text-align: center; }
+    .unsubscribe h1 { font-size: clamp(1.75rem, 4vw, 2.5rem); margin-bottom: 0.75rem; }
+    .unsubscribe p { color: var(--stone-500); font-family: var(--font-serif); margin-bottom: 2rem; }
+  `],
+})
+export class UnsubscribeComponent implements OnInit {
+  private readonly route = inject(ActivatedRoute);
+  private readonly api = inject(ApiService);
+  private readonly seo = inject(SeoService);
+
+  state = signal<'pending' | 'done' | 'failed'>('pending');
+  message = signal('');
+
+  ngOnInit() {
+    this.seo.updateSeo({
+      title: 'Unsubscribe',
+      description: 'Stop emails from Terroir AI.',
+      url: '/unsubscribe',
+      noindex: true,
+    });
+
+    const token = this.route.snapshot.queryParamMap.get('token');
+    if (!token) {
+      this.state.set('failed');
+      this.message.set('This unsubscribe link is missing its token.');
+      return;
+    }
+
+    this.api.unsubscribe(token).subscribe({
+      next: (res) => {
+        this.state.set('done');
+        this.message.set(res.message);
+      },
+      error: (err) => {
+        this.state.set('failed');
+        this.message.set(err.error?.error ?? 'Something went wrong. Please try again.');
+      },
+    });
+  }
+}
@@ -0,0 +1,78 @@
+import { Component, inject, signal, OnInit } from '@angular/core';
+import { ActivatedRoute, RouterLink } from '@angular/router';
+import { ApiService } from '@app/services/api.service';
+import { SeoService } from '@app/services/seo.service';
+import { AnalyticsService } from '@app/services/analytics.service';
+
+@Component({
+  selector: 'app-waitlist-confirm',
+  standalone: true,
+  imports: [RouterLink],
+  template: `
+    <section class="confirm" aria-label="Waitlist confirmation">
+      <div class="container">
+        @if (state() === 'pending') {
+          <p>Confirming your email…</p>
+        } @else if (state() === 'confirmed') {
+          <h1>You're confirmed</h1>
+          <p>{{ message() }}</p>
+          @if (referralUrl()) {
+            <p class="referral">Share your link to move up the list:<br /><code>{{ referralUrl() }}</code></p>
+          }
+          <a routerLink="/blog" class="btn btn-primary">Read the blog</a>
+        } @else {
+          <h1>We couldn't confirm that link</h1>
+          <p>{{ message() }}</p>
+          <a routerLink="/" fragment="pilot-signup" class="btn btn-primary">Sign up again</a>
+        }
+      </div>
+    </section>
+  `,
+  styles: [`
+    .confirm { padding: 8rem 0 6rem; text-align: center; }
+    .confirm h1 { font-size: clamp(1.75rem, 4vw, 2.5rem); margin-bottom: 0.75rem; }
+    .confirm p { color: var(--stone-500); font-family: var(--font-serif); margin-bottom: 2rem; }
+    .confirm .referral code { word-break: break-all; }
+  `],
+})
+export class WaitlistConfirmComponent implements OnInit {
+  private readonly route = inject(ActivatedRoute);
+  private readonly api = inject(ApiService);
+  private readonly seo = inject(SeoService);
+  private readonly analytics = inject(AnalyticsService);
+
+  state = signal<'pending' | 'confirmed' | 'failed'>('pending');
+  message = signal('');
+  referralUrl = signal('');
+
+  ngOnInit() {
+    this.seo.updateSeo({
+      title: 'Confirm your email',
+      description: 'Confirm your spot on the Terroir AI waitlist.',
+      url: '/waitlist/confirm',
+      noindex: true,
+    });
+
+    const token = this.route.snapshot.queryParamMap.get('token');
+    if (!token) {
+      this.state.set('failed');
+      this.message.set('This confirmation link is missing its token.');
+      return;
+    }
+
+    this.api.confirmWaitlist(token, this.analytics.currentVisitorId).subscribe({
+      next: (res) => {
+        this.state.set('confirmed');
+        this.message.set(res.message);
+        // The referral code is only handed out once the signup proves it owns the address
+        this.referralUrl.set(
+          `https://terroirai.com/?ref=${encodeURIComponent(res.referral_code)}&utm_source=referral&utm_medium=pilot_share&utm_campaign=pilot_2026`
+        );
+      },
+      error: (err) => {
+        this.state.set('failed');
+        this.message.set(err.error?.error ?? 'Something went wrong. Please try again.');
+      },
+    });
+  }
+}
@@ -40,6 +40,18 @@ export class AnalyticsService {
       });
   }
 
+  /** Persistent visitor ID, sent with form submissions to link browsing history */
+  get currentVisitorId(): string | undefined {
+    return this.visitorId || undefined;
+  }
+
+  /** Start over with a fresh visitor ID, e.g. after the visitor's data was erased */
+  resetVisitor(): void {
+    if (typeof window === 'undefined') return;
+    this.visitorId = this.generateId();
+    this.setCookie('terroir_vid', this.visitorId, 365);
+  }
+
   /** Track a page view — called automatically on route changes */
   trackPageView(path: string): void {
     const params = new URLSearchParams(window.location.search);
@@ -116,8 +128,9 @@ export class AnalyticsService {
     this.trackEvent('download_click', 'revenue', 'ios_app');
   }
 
-  trackBlogRead(slug: string): void {
-    this.trackEvent('blog_read', 'retention', slug);
+  /** Scroll-depth milestone (percent of the article read) for a blog post */
+  trackBlogRead(slug: string, scrollDepth: number): void {
+    this.trackEvent('blog_read', 'retention', slug, scrollDepth);
   }
 
   trackShare(platform: string): void {
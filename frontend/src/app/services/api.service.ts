@@ -14,13 +14,26 @@ export interface BlogPostSummary {
   author_name: string;
   read_time_minutes: number;
   published_at: string | null;
+  updated_at: string;
+  tags: string[];
 }
 
 export interface BlogPost extends BlogPostSummary {
   content_html: string;
   published: boolean;
   created_at: string;
-  updated_at: string;
+  related: BlogPostSummary[];
+}
+
+export interface BlogCategory {
+  category: string;
+  post_count: number;
+}
+
+export interface BlogTag {
+  slug: string;
+  name: string;
+  post_count: number;
 }
 
 export interface ContactRequest {
@@ -32,6 +45,7 @@ export interface ContactRequest {
   crop_type?: string;
   message: string;
   source?: string;
+  visitor_id?: string;
   website?: string;
   _form_loaded_at?: number;
 }
@@ -41,6 +55,8 @@ export interface WaitlistRequest {
   name?: string;
   company?: string;
   interest?: string;
+  ref?: string;
+  visitor_id?: string;
   website?: string;
   _form_loaded_at?: number;
 }
@@ -50,6 +66,29 @@ export interface ApiResponse {
   message: string;
 }
 
+export interface WaitlistResponse extends ApiResponse {
+  referral_code: string;
+}
+
+export interface WaitlistStatus {
+  referral_code: string;
+  interest: string;
+  confirmed: boolean;
+  position: number | null;
+  referral_count: number;
+}
+
+export interface ReferralLeader {
+  id: string;
+  email: string;
+  name: string | null;
+  company: string | null;
+  interest: string;
+  referral_code: string;
+  referral_count: number;
+  pending_referrals: number;
+}
+
 export interface AdminStats {
   total_contacts: number;
   total_waitlist: number;
@@ -68,6 +107,45 @@ export interface ContactSubmission {
   message: string;
   source: string;
   created_at: string;
+  status: 'new' | 'contacted' | 'qualified' | 'pilot' | 'closed_lost';
+  owner: string | null;
+  status_changed_at: string;
+  person_id: string | null;
+  visitor_id: string | null;
+  score: number | null;
+  score_explanation: string | null;
+  scored_at: string | null;
+  acreage_value: number | null;
+  acreage_unit: 'acres' | 'hectares' | null;
+  acreage_acres: number | null;
+  crop_type_normalized: string | null;
+}
+
+export interface Page<T> {
+  items: T[];
+  next_cursor: string | null;
+}
+
+/** Filters for the admin contact and waitlist lists; unset fields are omitted. */
+export interface AdminListQuery {
+  limit?: number;
+  cursor?: string;
+  q?: string;
+  status?: ContactSubmission['status'];
+  owner?: string;
+  source?: string;
+  interest?: string;
+  confirmed?: boolean;
+  crop_type?: string;
+  from?: string;
+  to?: string;
+  sort?: 'recent' | 'score';
+}
+
+function listParams(query: AdminListQuery): Record<string, string | number | boolean> {
+  return Object.fromEntries(
+    Object.entries(query).filter(([, value]) => value !== undefined && value !== ''),
+  );
 }
 
 export interface WaitlistEntry {
@@ -77,6 +155,80 @@ export interface WaitlistEntry {
   company: string | null;
   interest: string;
   created_at: string;
+  person_id: string | null;
+  visitor_id: string | null;
+  confirmation_sent_at: string | null;
+  confirmed_at: string | null;
+  referral_code: string;
+  referred_by: string | null;
+  pilot_status: PilotStatus;
+  cohort_id: string | null;
+  pilot_status_changed_at: string | null;
+}
+
+export type PilotStatus = 'pending' | 'invited' | 'accepted' | 'declined' | 'waitlisted';
+
+export interface PilotCohort {
+  id: string;
+  name: string;
+  interest: string;
+  region: string | null;
+  crop_focus: string | null;
+  capacity: number;
+  starts_on: string | null;
+  created_at: string;
+  updated_at: string;
+}
+
+export interface PilotCohortRequest {
+  name: string;
+  interest?: string;
+  region?: string;
+  crop_focus?: string;
+  capacity: number;
+  starts_on?: string;
+}
+
+export interface CohortFill extends PilotCohort {
+  invited: number;
+  accepted: number;
+  declined: number;
+  waitlisted: number;
+  seats_remaining: number;
+  fill_rate: number;
+}
+
+export type OutboxStatus = 'pending' | 'sent' | 'dead';
+
+export interface OutboxEmail {
+  id: string;
+  kind: string;
+  recipient: string;
+  subject: string;
+  contact_id: string | null;
+  waitlist_entry_id: string | null;
+  status: OutboxStatus;
+  attempts: number;
+  next_attempt_at: string;
+  last_error: string | null;
+  sent_at: string | null;
+  created_at: string;
+  updated_at: string;
+}
+
+export interface OutboxStats {
+  pending: number;
+  retrying: number;
+  sent: number;
+  dead: number;
+  oldest_pending_at: string | null;
+}
+
+export interface PilotStatusRequest {
+  status: PilotStatus;
+  cohort_id?: string;
+  changed_by?: string;
+  notify?: boolean;
 }
 
 @Injectable({ providedIn: 'root' })
@@ -84,10 +236,23 @@ export class ApiService {
   private readonly http = inject(HttpClient);
   private readonly baseUrl = environment.apiUrl;
 
-  getBlogPosts(limit = 10, offset = 0): Observable<BlogPostSummary[]> {
-    return this.http.get<BlogPostSummary[]>(
-      `${this.baseUrl}/blog?limit=${limit}&offset=${offset}`
-    );
+  getBlogPosts(
+    limit = 10,
+    offset = 0,
+    filter: { category?: string; tag?: string } = {},
+  ): Observable<BlogPostSummary[]> {
+    const params: Record<string, string | number> = { limit, offset };
+    if (filter.category) params['category'] = filter.category;
+    if (filter.tag) params['tag'] = filter.tag;
+    return this.http.get<BlogPostSummary[]>(`${this.baseUrl}/blog`, { params });
+  }
+
+  getBlogCategories(): Observable<BlogCategory[]> {
+    return this.http.get<BlogCategory[]>(`${this.baseUrl}/blog/categories`);
+  }
+
+  getBlogTags(): Observable<BlogTag[]> {
+    return this.http.get<BlogTag[]>(`${this.baseUrl}/blog/tags`);
   }
 
   getBlogPost(slug: string): Observable<BlogPost> {
@@ -102,23 +267,104 @@ export class ApiService {
     return this.http.post<ApiResponse>(`${this.baseUrl}/waitlist`, data);
   }
 
+  confirmWaitlist(token: string, visitorId?: string): Observable<WaitlistResponse> {
+    return this.http.post<WaitlistResponse>(`${this.baseUrl}/waitlist/confirm`, { token, visitor_id: visitorId });
+  }
+
+  unsubscribe(token: string): Observable<ApiResponse> {
+    return this.http.post<ApiResponse>(`${this.baseUrl}/privacy/unsubscribe`, { token });
+  }
+
+  requestErasure(email: string, formLoadedAt: number): Observable<ApiResponse> {
+    return this.http.post<ApiResponse>(`${this.baseUrl}/privacy/erasure-request`, {
+      email,
+      _form_loaded_at: formLoadedAt,
+    });
+  }
+
+  confirmErasure(token: string): Observable<ApiResponse> {
+    return this.http.post<ApiResponse>(`${this.baseUrl}/privacy/erase`, { token });
+  }
+
+  requestAccessExport(email: string, formLoadedAt: number): Observable<ApiResponse> {
+    return this.http.post<ApiResponse>(`${this.baseUrl}/privacy/access-request`, {
+      email,
+      _form_loaded_at: formLoadedAt,
+    });
+  }
+
+  downloadAccessExport(token: string, format: 'json' | 'zip'): Observable<Blob> {
+    return this.http.get(`${this.baseUrl}/privacy/export`, {
+      params: { token, format },
+      responseType: 'blob',
+    });
+  }
+
+  getWaitlistStatus(code: string): Observable<WaitlistStatus> {
+    return this.http.get<WaitlistStatus>(`${this.baseUrl}/waitlist/status/${encodeURIComponent(code)}`);
+  }
+
   getAdminStats(token: string): Observable<AdminStats> {
     return this.http.get<AdminStats>(`${this.baseUrl}/admin/stats`, {
       headers: { Authorization: `Bearer ${token}` },
     });
   }
 
-  getAdminContacts(token: string, limit = 100, offset = 0): Observable<ContactSubmission[]> {
-    return this.http.get<ContactSubmission[]>(
-      `${this.baseUrl}/admin/contacts?limit=${limit}&offset=${offset}`,
-      { headers: { Authorization: `Bearer ${token}` } },
-    );
+  getAdminContacts(token: string, query: AdminListQuery = {}): Observable<Page<ContactSubmission>> {
+    return this.http.get<Page<ContactSubmission>>(`${this.baseUrl}/admin/contacts`, {
+      headers: { Authorization: `Bearer ${token}` },
+      params: listParams(query),
+    });
+  }
+
+  getAdminWaitlist(token: string, query: AdminListQuery = {}): Observable<Page<WaitlistEntry>> {
+    return this.http.get<Page<WaitlistEntry>>(`${this.baseUrl}/admin/waitlist`, {
+      headers: { Authorization: `Bearer ${token}` },
+      params: listParams(query),
+    });
   }
 
-  getAdminWaitlist(token: string, limit = 100, offset = 0): Observable<WaitlistEntry[]> {
-    return this.http.get<WaitlistEntry[]>(
-      `${this.baseUrl}/admin/waitlist?limit=${limit}&offset=${offset}`,
-      { headers: { Authorization: `Bearer ${token}` } },
-    );
+  getAdminCohorts(token: string): Observable<CohortFill[]> {
+    return this.http.get<CohortFill[]>(`${this.baseUrl}/admin/cohorts`, {
+      headers: { Authorization: `Bearer ${token}` },
+    });
+  }
+
+  createCohort(token: string, data: PilotCohortRequest): Observable<PilotCohort> {
+    return this.http.post<PilotCohort>(`${this.baseUrl}/admin/cohorts`, data, {
+      headers: { Authorization: `Bearer ${token}` },
+    });
+  }
+
+  setPilotStatus(token: string, entryId: string, data: PilotStatusRequest): Observable<WaitlistEntry> {
+    return this.http.post<WaitlistEntry>(`${this.baseUrl}/admin/waitlist/${entryId}/pilot-status`, data, {
+      headers: { Authorization: `Bearer ${token}` },
+    });
+  }
+
+  getOutboxStats(token: string): Observable<OutboxStats> {
+    return this.http.get<OutboxStats>(`${this.baseUrl}/admin/email/outbox/stats`, {
+      headers: { Authorization: `Bearer ${token}` },
+    });
+  }
+
+  getOutbox(token: string, status?: OutboxStatus): Observable<OutboxEmail[]> {
+    return this.http.get<OutboxEmail[]>(`${this.baseUrl}/admin/email/outbox`, {
+      headers: { Authorization: `Bearer ${token}` },
+      params: status ? { status } : {},
+    });
+  }
+
+  retryEmail(token: string, id: string): Observable<OutboxEmail> {
+    return this.http.post<OutboxEmail>(`${this.baseUrl}/admin/email/outbox/${id}/retry`, null, {
+      headers: { Authorization: `Bearer ${token}` },
+    });
+  }
+
+  exportAdmin(token: string, table: 'contacts' | 'waitlist', format: 'csv' | 'xlsx' = 'csv'): Observable<Blob> {
+    return this.http.get(`${this.baseUrl}/admin/${table}/export?format=${format}`, {
+      headers: { Authorization: `Bearer ${token}` },
+      responseType: 'blob',
+    });
   }
 }
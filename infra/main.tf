@@ -52,6 +52,12 @@ variable "admin_token" {
   sensitive = true
 }
 
+variable "token_secret" {
+  type        = string
+  sensitive   = true
+  description = "HMAC key for signed links in emails (waitlist confirmation, unsubscribe). Rotating it invalidates links already sent."
+}
+
 variable "smtp_password" {
   type      = string
   sensitive = true
@@ -77,6 +83,7 @@ resource "google_project_service" "apis" {
     "artifactregistry.googleapis.com",
     "secretmanager.googleapis.com",
     "billingbudgets.googleapis.com",
+    "cloudscheduler.googleapis.com",
   ])
   service            = each.key
   disable_on_destroy = false
@@ -218,6 +225,19 @@ resource "google_secret_manager_secret_version" "admin_token" {
   secret_data = var.admin_token
 }
 
+resource "google_secret_manager_secret" "token_secret" {
+  secret_id = "token-secret"
+  replication {
+    auto {}
+  }
+  depends_on = [google_project_service.apis]
+}
+
+resource "google_secret_manager_secret_version" "token_secret" {
+  secret      = google_secret_manager_secret.token_secret.id
+  secret_data = var.token_secret
+}
+
 resource "google_secret_manager_secret" "smtp_password" {
   secret_id = "smtp-password"
   replication {
@@ -252,6 +272,12 @@ resource "google_cloud_run_v2_service" "backend" {
         name  = "ENVIRONMENT"
         value = "production"
       }
+      env {
+        # cpu_idle leaves no CPU for in-process loops between requests;
+        # Cloud Scheduler drives them instead (see Scheduled Jobs below)
+        name  = "BACKGROUND_WORKERS"
+        value = "false"
+      }
       env {
         name  = "CORS_ORIGIN"
         value = "https://${var.domain}"
@@ -274,6 +300,15 @@ resource "google_cloud_run_v2_service" "backend" {
           }
         }
       }
+      env {
+        name = "TOKEN_SECRET"
+        value_source {
+          secret_key_ref {
+            secret  = google_secret_manager_secret.token_secret.secret_id
+            version = "latest"
+          }
+        }
+      }
       env {
         name  = "APP_BASE_URL"
         value = "https://${var.domain}"
@@ -346,6 +381,7 @@ resource "google_cloud_run_v2_service" "backend" {
     google_project_service.apis,
     google_secret_manager_secret_version.db_url,
     google_secret_manager_secret_version.admin_token,
+    google_secret_manager_secret_version.token_secret,
     google_secret_manager_secret_version.smtp_password,
   ]
 }
@@ -485,6 +521,59 @@ resource "google_cloud_run_v2_service" "grafana" {
   depends_on = [google_project_service.apis]
 }
 
+# ─── Scheduled Jobs ─────────────────────────────
+# With cpu_idle = true and scale-to-zero, the backend has no CPU outside a
+# request, so background work runs when Cloud Scheduler calls the admin API.
+# Mail queued by a form submission goes out on the next minute's run.
+# COST: 3 jobs per billing account free, then $0.10/job/month. A call a
+# minute is ~43K requests and well under an hour of vCPU a month, inside the
+# Cloud Run free tier.
+
+resource "google_cloud_scheduler_job" "outbox_deliver" {
+  name             = "terroir-outbox-deliver"
+  description      = "Deliver queued email and retries"
+  region           = var.region
+  schedule         = "* * * * *"
+  time_zone        = "Etc/UTC"
+  attempt_deadline = "30s"
+
+  retry_config {
+    retry_count = 0 # The next run is a minute away
+  }
+
+  http_target {
+    http_method = "POST"
+    uri         = "${google_cloud_run_v2_service.backend.uri}/api/v1/admin/email/outbox/deliver"
+    headers = {
+      # Stored in the job config; anyone who can view scheduler jobs can read it
+      Authorization = "Bearer ${var.admin_token}"
+    }
+  }
+
+  depends_on = [google_project_service.apis]
+}
+
+# Sends nothing until the hour set by digest_hour_utc in the notification
+# routing config has passed, so hourly is as late as a digest can be.
+resource "google_cloud_scheduler_job" "digest_send" {
+  name             = "terroir-digest-send"
+  description      = "Queue daily notification digests once their hour has passed"
+  region           = var.region
+  schedule         = "5 * * * *"
+  time_zone        = "Etc/UTC"
+  attempt_deadline = "30s"
+
+  http_target {
+    http_method = "POST"
+    uri         = "${google_cloud_run_v2_service.backend.uri}/api/v1/admin/email/digest/send"
+    headers = {
+      Authorization = "Bearer ${var.admin_token}"
+    }
+  }
+
+  depends_on = [google_project_service.apis]
+}
+
 # ─── Public access ──────────────────────────────
 
 resource "google_cloud_run_v2_service_iam_member" "frontend_public" {